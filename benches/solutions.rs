@@ -0,0 +1,42 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc::{registry, solver::Options};
+
+/// Benchmarks parsing and both parts of every registered puzzle whose input is present.
+///
+/// Filter like with the tests, for example `cargo bench -- 2024/day14`
+fn solutions(c: &mut Criterion) {
+    for puzzle in registry::puzzles() {
+        let Ok(input) = puzzle.input(None) else {
+            eprintln!("skipped {}: the input is missing", puzzle.input_file(None));
+            continue;
+        };
+
+        let parameters = match puzzle.parameters(None, &Options::default()) {
+            Ok(parameters) => parameters,
+            Err(error) => {
+                eprintln!("skipped {}: {error}", puzzle.input_file(None));
+                continue;
+            }
+        };
+
+        let parsed = match (puzzle.prepare)(&input, &parameters) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                eprintln!("skipped {}: {error}", puzzle.input_file(None));
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("{}/day{}", puzzle.year, puzzle.day));
+
+        group.bench_function("parse", |b| b.iter_with_large_drop(|| (puzzle.prepare)(&input, &parameters)));
+        group.bench_function("part1", |b| b.iter(|| parsed.part1()));
+        group.bench_function("part2", |b| b.iter(|| parsed.part2()));
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
@@ -0,0 +1,120 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell, ops::Add};
+
+use serde::Serialize;
+
+/// Whether the runner was built with the `memory` feature, which installs the counting allocator
+pub const AVAILABLE: bool = cfg!(feature = "memory");
+
+/// A global allocator that counts the allocations of the current thread while they are tracked.
+///
+/// It only counts once it is installed by the binary, which it does with the `memory` feature,
+/// otherwise all tracked allocations are zero
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+/// The allocations of a step
+#[derive(Clone, Copy, Default, Debug, Serialize, PartialEq, Eq)]
+pub struct Allocations {
+    /// How many times memory was allocated or reallocated
+    pub count: u64,
+    /// The bytes requested by all allocations together
+    pub bytes: u64,
+    /// The most bytes that were allocated by the step at the same time
+    pub peak: u64,
+}
+
+impl Add for Allocations {
+    type Output = Allocations;
+
+    /// Combines the allocations of consecutive steps. The peak is the highest peak of a single step
+    fn add(self, rhs: Self) -> Self::Output {
+        Allocations {
+            count: self.count + rhs.count,
+            bytes: self.bytes + rhs.bytes,
+            peak: self.peak.max(rhs.peak),
+        }
+    }
+}
+
+/// The counters of a thread. Memory allocated before tracking started and freed during it makes the live bytes negative
+#[derive(Clone, Copy)]
+struct Counter {
+    tracking: bool,
+    count: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+impl Counter {
+    const IDLE: Counter = Counter { tracking: false, count: 0, bytes: 0, live: 0, peak: 0 };
+}
+
+thread_local! {
+    // Constant and without destructor, so accessing it from the allocator never allocates
+    static COUNTER: Cell<Counter> = const { Cell::new(Counter::IDLE) };
+}
+
+/// Updates the counter of the current thread, if it is tracking
+fn count(allocated: usize, freed: usize) {
+    let _ = COUNTER.try_with(|counter| {
+        let mut current = counter.get();
+
+        if !current.tracking {
+            return;
+        }
+
+        if allocated > 0 {
+            current.count += 1;
+            current.bytes += allocated as u64;
+        }
+
+        current.live += allocated as i64 - freed as i64;
+        current.peak = current.peak.max(current.live);
+        counter.set(current);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            count(layout.size(), 0);
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            count(layout.size(), 0);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        count(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            count(new_size, layout.size());
+        }
+        new_pointer
+    }
+}
+
+/// Runs the function while counting the allocations of the current thread
+pub fn track<T, F>(function: F) -> (T, Allocations) where F: FnOnce() -> T {
+    COUNTER.set(Counter { tracking: true, ..Counter::IDLE });
+    let value = function();
+    let counter = COUNTER.replace(Counter::IDLE);
+
+    (value, Allocations { count: counter.count, bytes: counter.bytes, peak: counter.peak as u64 })
+}
@@ -1,6 +1,8 @@
-use clap::Parser;
+use std::{iter, time::Duration};
 
-use crate::{y2015, y2024};
+use clap::{Parser, Subcommand};
+
+use crate::{config, error::Error, fetch, fingerprint, formatting::Redaction, isolation, parameters::parse_assignment, registry::{self, Puzzle}, report::{DayReport, Format, YearReport}, solver::Options};
 
 /// Solve all implemented AOC Puzzles \
 /// Keep your input files under "./inputs/{year}/day{day}.txt" \
@@ -8,33 +10,419 @@ use crate::{y2015, y2024};
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Read the defaults of the runner from this file, if it exists
+    #[arg(long, value_name = "FILE", default_value = config::CONFIG_FILE)]
+    pub config: String,
+
     /// The years to run
     #[arg(short, long)]
     pub year: Vec<u16>,
 
-    /// The days to run (requires year)
-    #[arg(short, long, requires("year"))]
+    /// The days to run, in every selected year
+    #[arg(short, long)]
     pub day: Vec<u8>,
 
+    /// Select days per year, like 2015:3,2024:10-20 or 2024:*
+    #[arg(short, long = "select", value_name = "SELECTION", value_delimiter = ',', value_parser = parse_selector, conflicts_with_all = ["year", "day"])]
+    pub selection: Vec<Selector>,
+
     /// Redact solutions from output
     #[arg(short, long)]
     pub redact: bool,
 
-    /// Output to ./output.txt in addition to the terminal
+    /// Show a salted fingerprint of each answer instead of the answer, and record fingerprints into expect files
+    #[arg(long, conflicts_with("redact"))]
+    pub fingerprint: bool,
+
+    /// Output to ./output.txt, or the output file of the configuration, in addition to the terminal
+    #[arg(short, long)]
+    pub output: bool,
+
+    /// List all available puzzles instead of solving them
+    #[arg(short, long)]
+    pub list: bool,
+
+    /// Benchmark the input parsing and each part over this many runs, after a short warmup
+    #[arg(short, long, value_name = "RUNS")]
+    pub bench: Option<u32>,
+
+    /// Only run this part of each puzzle
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Give up on a puzzle after this many seconds, 0 waits forever
+    #[arg(short, long, value_name = "SECONDS", default_value_t = 60)]
+    pub timeout: u64,
+
+    /// Solve this many days at the same time
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    pub jobs: usize,
+
+    /// Count the allocations, allocated bytes and peak memory of the input parsing and each part (needs the memory feature)
     #[arg(short, long)]
-    pub output: bool
+    pub memory: bool,
+
+    /// Override a parameter of the selected puzzles, like the grid size to solve an example
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_assignment)]
+    pub parameters: Vec<(String, i64)>,
+
+    /// The format of the output
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Save the timings of this run as a baseline with the given name
+    #[arg(long, value_name = "NAME")]
+    pub save_baseline: Option<String>,
+
+    /// Compare the timings of this run against the baseline with the given name
+    #[arg(long, value_name = "NAME")]
+    pub baseline: Option<String>,
+
+    /// Fail the run if the time of a day regressed by more than this percentage compared to the baseline
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0, requires("baseline"))]
+    pub regression_threshold: f64,
+
+    /// Write the answers of the selected days into their expect files
+    #[arg(long)]
+    pub record: bool,
+
+    /// Overwrite expect files that contain different answers when recording
+    #[arg(long, requires("record"))]
+    pub force: bool,
+
+    /// Solve again whenever an input or expect file of the selected days changes, highlighting what changed
+    #[arg(short, long, conflicts_with_all = ["list", "output", "format", "save_baseline", "record"])]
+    pub watch: bool,
+
+    /// Rebuild and restart the runner when a source file changes while watching
+    #[arg(long, requires("watch"))]
+    pub rebuild: bool
+}
+
+/// Tasks besides solving the puzzles
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Create the module of a new day, together with a placeholder input and expect file
+    New {
+        /// The year of the puzzle
+        #[arg(short, long)]
+        year: u16,
+
+        /// The day of the puzzle
+        #[arg(short, long)]
+        day: u8,
+
+        /// The title of the puzzle
+        #[arg(short, long, default_value = "Unnamed")]
+        title: String,
+    },
+    /// Download the inputs of the selected puzzles that are missing
+    Fetch {
+        /// The years to fetch
+        #[arg(short, long)]
+        year: Vec<u16>,
+
+        /// The days to fetch, in every selected year
+        #[arg(short, long)]
+        day: Vec<u8>,
+
+        /// Select days per year, like 2015:3,2024:10-20 or 2024:*
+        #[arg(short, long = "select", value_name = "SELECTION", value_delimiter = ',', value_parser = parse_selector, conflicts_with_all = ["year", "day"])]
+        selection: Vec<Selector>,
+
+        /// The session token, which is otherwise read from ./.session
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: Option<String>,
+
+        /// The site to download from, for example a local mock server
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Wait at least this many seconds between two downloads
+        #[arg(long, value_name = "SECONDS", default_value = "3", value_parser = parse_interval)]
+        interval: Duration,
+    },
 }
 
 impl Args {
-    pub fn years(&self) -> Vec<String> {
-        if self.year.is_empty() {
-            vec![y2015::solve_all(self), y2024::solve_all(self)]
+    /// Solves all selected years. The selection and parameter overrides are validated before anything is solved
+    pub fn years(&self) -> Result<Vec<YearReport>, Error> {
+        let selection = select(&self.year, &self.day, &self.selection)?;
+
+        for (name, _) in &self.parameters {
+            let declared = selection.iter()
+                .flat_map(|(_, puzzles)| puzzles)
+                .any(|puzzle| puzzle.parameters.iter().any(|parameter| parameter.name == name));
+
+            if !declared {
+                return Err(Error::InvalidParameter(format!("{name} is not a parameter of any selected puzzle")));
+            }
+        }
+
+        let selection: Vec<_> = selection.into_iter()
+            .map(|(year, puzzles)| (year, with_examples(puzzles)))
+            .collect();
+
+        let inputs: Vec<(&'static Puzzle, Option<String>)> = selection.iter().flat_map(|(_, inputs)| inputs.iter().cloned()).collect();
+        let mut results = isolation::solve_all(&inputs, &self.options(), self.jobs).into_iter();
+        let jobs = isolation::workers(self.jobs, inputs.len());
+
+        Ok(selection.into_iter().map(|(year, inputs)| {
+            let days = inputs.into_iter()
+                .zip(results.by_ref())
+                .map(|((puzzle, example), result)| DayReport { puzzle, example, result })
+                .collect();
+
+            YearReport { year, days, jobs }
+        }).collect())
+    }
+
+    /// How the answers are shown. Fingerprints need the salt, which is an error if there is none
+    pub fn redaction(&self) -> Result<Redaction, String> {
+        if self.fingerprint {
+            fingerprint::salt().map(Redaction::Fingerprinted)
+        } else if self.redact {
+            Ok(Redaction::Hidden)
         } else {
-            self.year.iter().map(|year| match year {
-                2015 => y2015::solve_all(self),
-                2024 => y2024::solve_all(self),
-                _ => panic!("Unknown year {year}")
-            }).collect()
+            Ok(Redaction::Shown)
+        }
+    }
+
+    /// The options the selected puzzles are evaluated with
+    pub fn options(&self) -> Options {
+        Options {
+            bench: self.bench,
+            part: self.part,
+            timeout: (self.timeout > 0).then(|| Duration::from_secs(self.timeout)),
+            parameters: self.parameters.clone(),
+            memory: self.memory,
+        }
+    }
+}
+
+/// The days of a year that a selection expression selects
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Days {
+    /// All registered days
+    All,
+    /// A single day, which has to be registered
+    Single(u8),
+    /// The registered days in the range, including both ends
+    Range(u8, u8),
+}
+
+/// A single part of a selection expression, which selects days of a year
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Selector {
+    pub year: u16,
+    pub days: Days,
+}
+
+/// Parses a part of a selection expression. A year without days selects all of its days.
+/// The days are only checked against the registry once the puzzles are selected
+///
+/// ```
+/// use aoc::args::{parse_selector, Days, Selector};
+///
+/// assert_eq!(Ok(Selector { year: 2015, days: Days::Single(3) }), parse_selector("2015:3"));
+/// assert_eq!(Ok(Selector { year: 2024, days: Days::Range(10, 20) }), parse_selector("2024:10-20"));
+/// assert_eq!(Ok(Selector { year: 2024, days: Days::All }), parse_selector("2024:*"));
+/// assert!(parse_selector("2024:26").is_err());
+/// ```
+pub fn parse_selector(selector: &str) -> Result<Selector, String> {
+    let invalid = || format!("invalid selection {selector}, expected YEAR, YEAR:DAY, YEAR:FIRST-LAST or YEAR:*");
+
+    let (year, days) = selector.trim().split_once(':').unwrap_or((selector.trim(), "*"));
+    let year = year.parse().map_err(|_| invalid())?;
+
+    let day = |day: &str| -> Result<u8, String> {
+        match day.trim().parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("invalid day {day} in selection {selector}, days go from 1 to 25")),
+        }
+    };
+
+    let days = match days.trim() {
+        "*" => Days::All,
+        days => match days.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (day(first)?, day(last)?);
+
+                if first > last {
+                    return Err(format!("invalid range in selection {selector}, the first day comes after the last"));
+                }
+
+                Days::Range(first, last)
+            },
+            None if days.is_empty() => return Err(invalid()),
+            None => Days::Single(day(days)?),
+        },
+    };
+
+    Ok(Selector { year, days })
+}
+
+/// Looks up the puzzles of the selection expressions, grouped by year in the order the years are first selected.
+/// Days that are selected more than once are only solved once
+fn resolve(selectors: &[Selector]) -> Result<Vec<(u16, Vec<&'static Puzzle>)>, Error> {
+    let mut selection: Vec<(u16, Vec<&'static Puzzle>)> = vec![];
+
+    for &Selector { year, days } in selectors {
+        let puzzles = registry::year(year);
+
+        if puzzles.is_empty() {
+            return Err(Error::UnknownYear(year));
         }
+
+        let selected = match days {
+            Days::All => puzzles,
+            Days::Single(day) => vec![registry::find(year, day).ok_or(Error::UnknownDay(year, day))?],
+            Days::Range(first, last) => {
+                let puzzles: Vec<_> = puzzles.into_iter().filter(|puzzle| (first..=last).contains(&puzzle.day)).collect();
+
+                if puzzles.is_empty() {
+                    return Err(Error::UnknownDays(year, first, last));
+                }
+
+                puzzles
+            },
+        };
+
+        let index = match selection.iter().position(|(selected, _)| *selected == year) {
+            Some(index) => index,
+            None => {
+                selection.push((year, vec![]));
+                selection.len() - 1
+            }
+        };
+
+        let existing = &mut selection[index].1;
+
+        for puzzle in selected {
+            if !existing.iter().any(|existing| existing.day == puzzle.day) {
+                existing.push(puzzle);
+            }
+        }
+
+        existing.sort_by_key(|puzzle| puzzle.day);
     }
-}
\ No newline at end of file
+
+    Ok(selection)
+}
+
+/// Parses a number of seconds that fits into a duration, which rejects negative, infinite and NaN values
+///
+/// ```
+/// use std::time::Duration;
+/// use aoc::args::parse_interval;
+///
+/// assert_eq!(Ok(Duration::from_millis(2500)), parse_interval("2.5"));
+/// assert!(parse_interval("-1").is_err());
+/// assert!(parse_interval("inf").is_err());
+/// assert!(parse_interval("NaN").is_err());
+/// assert!(parse_interval("1e30").is_err());
+/// ```
+pub fn parse_interval(seconds: &str) -> Result<Duration, String> {
+    let seconds: f64 = seconds.parse().map_err(|_| format!("invalid number of seconds {seconds}"))?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("{seconds} is not a valid number of seconds"))
+}
+
+/// Looks up the selected puzzles, grouped by year. Selection expressions take precedence over the years and days.
+/// No days select all days of the years, and no years select the default years of the configuration.
+/// Without default years, days are looked up in every registered year that has them
+pub fn select(years: &[u16], days: &[u8], selectors: &[Selector]) -> Result<Vec<(u16, Vec<&'static Puzzle>)>, Error> {
+    if !selectors.is_empty() {
+        return resolve(selectors);
+    }
+
+    let defaults = &config::get().years;
+
+    let years = if !years.is_empty() {
+        years.to_vec()
+    } else if !defaults.is_empty() {
+        defaults.clone()
+    } else if days.is_empty() {
+        registry::years()
+    } else {
+        return across_years(days);
+    };
+
+    years.into_iter()
+        .map(|year| puzzles(year, days).map(|puzzles| (year, puzzles)))
+        .collect()
+}
+
+/// Looks up the days in every registered year, skipping the years that do not have them.
+/// Only a day that no year has is an error
+fn across_years(days: &[u8]) -> Result<Vec<(u16, Vec<&'static Puzzle>)>, Error> {
+    if let Some(&day) = days.iter().find(|&&day| registry::years().into_iter().all(|year| registry::find(year, day).is_none())) {
+        return Err(Error::UnregisteredDay(day));
+    }
+
+    Ok(registry::years().into_iter()
+        .map(|year| (year, days.iter().filter_map(|&day| registry::find(year, day)).collect::<Vec<_>>()))
+        .filter(|(_, puzzles)| !puzzles.is_empty())
+        .collect())
+}
+
+/// Looks up the selected puzzles of a year
+fn puzzles(year: u16, days: &[u8]) -> Result<Vec<&'static Puzzle>, Error> {
+    let puzzles = registry::year(year);
+
+    if puzzles.is_empty() {
+        return Err(Error::UnknownYear(year));
+    }
+
+    if days.is_empty() {
+        return Ok(puzzles);
+    }
+
+    days.iter()
+        .map(|&day| registry::find(year, day).ok_or(Error::UnknownDay(year, day)))
+        .collect()
+}
+
+/// Pairs every puzzle with its input, followed by its named examples
+fn with_examples(puzzles: Vec<&'static Puzzle>) -> Vec<(&'static Puzzle, Option<String>)> {
+    puzzles.into_iter()
+        .flat_map(|puzzle| {
+            let examples = puzzle.examples().into_iter().map(Some);
+            iter::once(None).chain(examples).map(move |example| (puzzle, example))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected(selection: Vec<(u16, Vec<&'static Puzzle>)>) -> Vec<(u16, Vec<u8>)> {
+        selection.into_iter()
+            .map(|(year, puzzles)| (year, puzzles.iter().map(|puzzle| puzzle.day).collect()))
+            .collect()
+    }
+
+    // Without years, select falls back to the default years of the configuration first, which the tests should not depend on
+    #[test]
+    fn days_without_years_run_in_every_year() {
+        assert_eq!(vec![(2015, vec![1]), (2024, vec![1])], selected(across_years(&[1]).unwrap()));
+    }
+
+    #[test]
+    fn days_without_years_skip_years_without_them() {
+        assert_eq!(vec![(2015, vec![6]), (2024, vec![6, 7])], selected(across_years(&[6, 7]).unwrap()));
+    }
+
+    #[test]
+    fn days_without_years_must_exist_in_some_year() {
+        assert!(matches!(across_years(&[1, 26]), Err(Error::UnregisteredDay(26))));
+    }
+
+    #[test]
+    fn days_with_years_must_exist_in_each_year() {
+        assert!(matches!(select(&[2015], &[7], &[]), Err(Error::UnknownDay(2015, 7))));
+    }
+}
@@ -0,0 +1,226 @@
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{formatting::Solution, report::YearReport};
+
+/// The directory baselines are stored in
+const BASELINE_DIRECTORY: &str = "baselines";
+
+/// The timings of a single day, in nanoseconds.
+/// Parts that were never run with this baseline have no timing
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub input: u128,
+    #[serde(default)]
+    pub part_1: Option<u128>,
+    #[serde(default)]
+    pub part_2: Option<u128>,
+}
+
+impl DayTimings {
+    /// Pairs the times of the solution with their baseline times, in the order input, part 1, part 2 and total.
+    /// Parts that are missing on either side are left out, also from the total, so only like is compared with like
+    pub fn compare(&self, solution: &Solution) -> [Option<(u128, u128)>; 4] {
+        let part = |answer: &Option<String>, time: u128, baseline: Option<u128>| {
+            answer.as_ref().and(baseline).map(|baseline| (time, baseline))
+        };
+
+        let input = (solution.input_time.median, self.input);
+        let part_1 = part(&solution.solution_1, solution.time_1.median, self.part_1);
+        let part_2 = part(&solution.solution_2, solution.time_2.median, self.part_2);
+
+        let total = [part_1, part_2].into_iter().flatten()
+            .fold(input, |(time, baseline), part| (time + part.0, baseline + part.1));
+
+        [Some(input), part_1, part_2, Some(total)]
+    }
+}
+
+/// A named set of timings that later runs can be compared against
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct Baseline {
+    pub days: Vec<DayTimings>,
+}
+
+/// The difference between a time and its baseline
+#[derive(Clone, Copy, Debug)]
+pub struct Delta {
+    pub absolute: i128,
+    pub percentage: f64,
+}
+
+impl Delta {
+    pub fn new(time: u128, baseline: u128) -> Delta {
+        let absolute = time as i128 - baseline as i128;
+        let percentage = if baseline == 0 { 0.0 } else { absolute as f64 / baseline as f64 * 100.0 };
+
+        Delta { absolute, percentage }
+    }
+
+    /// Checks whether the time got slower by more than the threshold, given in percent
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percentage > threshold
+    }
+}
+
+impl Baseline {
+    /// The file a baseline is stored in
+    pub fn path(name: &str) -> PathBuf {
+        PathBuf::from(BASELINE_DIRECTORY).join(format!("{name}.json"))
+    }
+
+    /// Loads a baseline, failing if it was never saved
+    pub fn load(name: &str) -> Result<Baseline, String> {
+        let path = Self::path(name);
+        let content = fs::read_to_string(&path).map_err(|_| format!("Baseline {name} not found at {}", path.display()))?;
+
+        serde_json::from_str(&content).map_err(|error| format!("Baseline {name} is invalid: {error}"))
+    }
+
+    /// Saves the timings of all solved days under the name.
+    /// Days of an existing baseline that were not run this time are kept
+    pub fn save(name: &str, reports: &[YearReport]) -> Result<(), String> {
+        let mut baseline = Self::load(name).unwrap_or_default();
+        baseline.update(reports);
+
+        let path = Self::path(name);
+        let content = serde_json::to_string_pretty(&baseline).expect("Baselines are always serializable");
+
+        fs::create_dir_all(BASELINE_DIRECTORY)
+            .and_then(|_| fs::write(&path, content))
+            .map_err(|error| format!("Could not save baseline to {}: {error}", path.display()))
+    }
+
+    /// Takes over the timings of all solved days. Examples are left out, only the real inputs are timed
+    pub fn update(&mut self, reports: &[YearReport]) {
+        for report in reports {
+            for day in &report.days {
+                let (Ok(solution), None) = (&day.result, &day.example) else {
+                    continue;
+                };
+
+                let existing = self.get(report.year, day.puzzle.day).copied();
+
+                let timings = DayTimings {
+                    year: report.year,
+                    day: day.puzzle.day,
+                    input: solution.input_time.median,
+                    part_1: solution.solution_1.as_ref().map(|_| solution.time_1.median).or(existing.and_then(|existing| existing.part_1)),
+                    part_2: solution.solution_2.as_ref().map(|_| solution.time_2.median).or(existing.and_then(|existing| existing.part_2)),
+                };
+
+                match self.get_mut(report.year, day.puzzle.day) {
+                    Some(existing) => *existing = timings,
+                    None => self.days.push(timings),
+                }
+            }
+        }
+
+        self.days.sort_by_key(|timings| (timings.year, timings.day));
+    }
+
+    /// Looks up the timings of a day
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayTimings> {
+        self.days.iter().find(|timings| timings.year == year && timings.day == day)
+    }
+
+    fn get_mut(&mut self, year: u16, day: u8) -> Option<&mut DayTimings> {
+        self.days.iter_mut().find(|timings| timings.year == year && timings.day == day)
+    }
+
+    /// Finds all days whose total time regressed by more than the threshold, given in percent
+    pub fn regressions(&self, reports: &[YearReport], threshold: f64) -> Vec<(u16, u8, Delta)> {
+        let mut regressions = vec![];
+
+        for report in reports {
+            for day in &report.days {
+                let (Ok(solution), None, Some(timings)) = (&day.result, &day.example, self.get(report.year, day.puzzle.day)) else {
+                    continue;
+                };
+
+                let [.., Some((total, baseline_total))] = timings.compare(solution) else {
+                    continue;
+                };
+
+                let delta = Delta::new(total, baseline_total);
+
+                if delta.is_regression(threshold) {
+                    regressions.push((report.year, day.puzzle.day, delta));
+                }
+            }
+        }
+
+        regressions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formatting::Timing;
+
+    use super::*;
+
+    fn solution(input: u128, part_1: Option<u128>, part_2: Option<u128>) -> Solution {
+        Solution {
+            input_time: Timing::single(input),
+            time_1: Timing::single(part_1.unwrap_or_default()),
+            time_2: Timing::single(part_2.unwrap_or_default()),
+            ..Solution::answered(part_1.map(|_| "1"), part_2.map(|_| "2"))
+        }
+    }
+
+    fn report(example: Option<&str>, solution: Solution) -> Vec<YearReport> {
+        vec![YearReport::single(example, Ok(solution))]
+    }
+
+    fn baseline(input: u128, part_1: Option<u128>, part_2: Option<u128>) -> Baseline {
+        Baseline { days: vec![DayTimings { year: 2015, day: 1, input, part_1, part_2 }] }
+    }
+
+    #[test]
+    fn compares_only_parts_on_both_sides() {
+        let compared = baseline(10, Some(20), None).days[0].compare(&solution(11, Some(22), Some(30)));
+
+        assert_eq!([Some((11, 10)), Some((22, 20)), None, Some((33, 30))], compared);
+    }
+
+    #[test]
+    fn regressions_exceed_the_threshold() {
+        assert!(Delta::new(111, 100).is_regression(10.0));
+        assert!(!Delta::new(110, 100).is_regression(10.0));
+        assert!(!Delta::new(90, 100).is_regression(0.0));
+    }
+
+    #[test]
+    fn zero_baselines_never_regress() {
+        assert!(!Delta::new(100, 0).is_regression(0.0));
+    }
+
+    #[test]
+    fn finds_regressed_days() {
+        let reports = report(None, solution(60, Some(60), None));
+        let baseline = baseline(50, Some(50), Some(1000));
+
+        assert_eq!(vec![(2015, 1)], baseline.regressions(&reports, 10.0).iter().map(|&(year, day, _)| (year, day)).collect::<Vec<_>>());
+        assert!(baseline.regressions(&reports, 25.0).is_empty());
+    }
+
+    #[test]
+    fn examples_are_not_compared() {
+        let reports = report(Some("small"), solution(1000, Some(1000), Some(1000)));
+
+        assert!(baseline(1, Some(1), Some(1)).regressions(&reports, 0.0).is_empty());
+    }
+
+    #[test]
+    fn updates_keep_parts_that_were_not_run() {
+        let mut baseline = baseline(50, Some(50), Some(50));
+        baseline.update(&report(None, solution(10, None, Some(20))));
+
+        let timings = baseline.get(2015, 1).unwrap();
+        assert_eq!((10, Some(50), Some(20)), (timings.input, timings.part_1, timings.part_2));
+    }
+}
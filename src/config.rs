@@ -0,0 +1,228 @@
+use std::{collections::BTreeMap, fs, io::ErrorKind, sync::OnceLock};
+
+use serde::Deserialize;
+
+use crate::registry;
+
+/// The configuration file that is read from the working directory, unless another one is given
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Defaults of the runner that can be changed per project in `aoc.toml`.
+/// Everything is optional, and flags on the command line take precedence
+///
+/// ```toml
+/// years = [2024]
+///
+/// [paths]
+/// inputs = "inputs"
+/// expect = "expect"
+/// output = "output.txt"
+///
+/// [colors]
+/// fast = 1.0
+/// slow = 10.0
+///
+/// [[puzzle]]
+/// year = 2024
+/// day = 14
+/// parameters = { width = 101, height = 103 }
+/// budget = 2.5
+/// ```
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The years to run if none are selected
+    pub years: Vec<u16>,
+    pub paths: Paths,
+    pub colors: Colors,
+    /// Settings of single puzzles
+    #[serde(rename = "puzzle")]
+    pub puzzles: Vec<PuzzleConfig>,
+}
+
+/// Where the runner reads and writes its files, relative to the working directory
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    /// The directory of the inputs, examples and parameter files, with a directory per year
+    pub inputs: String,
+    /// The directory of the expect files, with a directory per year
+    pub expect: String,
+    /// The file `--output` writes to
+    pub output: String,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            inputs: "inputs".to_string(),
+            expect: "expect".to_string(),
+            output: "output.txt".to_string(),
+        }
+    }
+}
+
+/// The times in milliseconds up to which a time is shown in green and yellow, slower times are red
+#[derive(Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Colors {
+    pub fast: f64,
+    pub slow: f64,
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Colors { fast: 1.0, slow: 10.0 }
+    }
+}
+
+/// The settings of a single puzzle
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct PuzzleConfig {
+    pub year: u16,
+    pub day: u8,
+    /// Overrides of the declared parameters, for the input and all examples
+    #[serde(default)]
+    pub parameters: BTreeMap<String, i64>,
+    /// The most time in milliseconds the input parsing and both parts may take together
+    pub budget: Option<f64>,
+}
+
+impl Config {
+    /// Reads the configuration file. Without a file, all defaults are used
+    pub fn read(path: &str) -> Result<Config, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Config::default()),
+            Err(error) => return Err(format!("Could not read {path}: {error}")),
+        };
+
+        let config: Config = toml::from_str(&content).map_err(|error| format!("Invalid configuration in {path}: {error}"))?;
+        config.validate().map_err(|error| format!("Invalid configuration in {path}: {error}"))?;
+
+        Ok(config)
+    }
+
+    /// Checks the settings against the registered puzzles, so a typo does not go unnoticed
+    fn validate(&self) -> Result<(), String> {
+        if !(0.0..=self.colors.slow).contains(&self.colors.fast) {
+            return Err("the fast color threshold has to be between 0 and the slow one".to_string());
+        }
+
+        if let Some(year) = self.years.iter().find(|&&year| registry::year(year).is_empty()) {
+            return Err(format!("there are no puzzles for {year}"));
+        }
+
+        for settings in &self.puzzles {
+            let Some(puzzle) = registry::find(settings.year, settings.day) else {
+                return Err(format!("there is no puzzle for day {} of {}", settings.day, settings.year));
+            };
+
+            for name in settings.parameters.keys() {
+                if !puzzle.parameters.iter().any(|parameter| parameter.name == name) {
+                    return Err(format!("{name} is not a parameter of day {} of {}", settings.day, settings.year));
+                }
+            }
+
+            if settings.budget.is_some_and(|budget| budget <= 0.0) {
+                return Err(format!("the budget of day {} of {} has to be positive", settings.day, settings.year));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The settings of a puzzle, if there are any
+    pub fn puzzle(&self, year: u16, day: u8) -> Option<&PuzzleConfig> {
+        self.puzzles.iter().find(|settings| settings.year == year && settings.day == day)
+    }
+
+    /// The time budget of a puzzle in nanoseconds
+    pub fn budget(&self, year: u16, day: u8) -> Option<u128> {
+        self.puzzle(year, day)?.budget.map(milliseconds)
+    }
+}
+
+/// Converts milliseconds from the configuration into nanoseconds
+pub fn milliseconds(milliseconds: f64) -> u128 {
+    (milliseconds * 1_000_000.0) as u128
+}
+
+/// Reads the configuration from the given file. This has to happen before the configuration is first used,
+/// otherwise the configuration that is already in use is kept
+pub fn load(path: &str) -> Result<&'static Config, String> {
+    let config = Config::read(path)?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// The configuration of the runner. Unless it was loaded before, it is read from `aoc.toml`, which panics if the file is invalid
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| Config::read(CONFIG_FILE).unwrap_or_else(|error| panic!("{error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<Config, String> {
+        let config: Config = toml::from_str(content).map_err(|error| error.to_string())?;
+        config.validate().map(|_| config)
+    }
+
+    #[test]
+    fn accepts_the_documented_example() {
+        let config = parse("
+            years = [2024]
+
+            [paths]
+            inputs = \"puzzles\"
+
+            [colors]
+            fast = 2.0
+
+            [[puzzle]]
+            year = 2024
+            day = 14
+            parameters = { width = 101, height = 103 }
+            budget = 2.5
+        ").unwrap();
+
+        assert_eq!("puzzles", config.paths.inputs);
+        assert_eq!("expect", config.paths.expect);
+        assert_eq!(10.0, config.colors.slow);
+        assert_eq!(Some(2_500_000), config.budget(2024, 14));
+        assert_eq!(None, config.budget(2024, 1));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(parse("year = [2024]").is_err());
+        assert!(parse("[paths]\ninput = \"puzzles\"").is_err());
+        assert!(parse("[[puzzle]]\nyear = 2024\nday = 14\nbudgets = 1.0").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_puzzles_and_parameters() {
+        assert!(parse("years = [1999]").is_err());
+        assert!(parse("[[puzzle]]\nyear = 2024\nday = 26").is_err());
+        assert!(parse("[[puzzle]]\nyear = 2024\nday = 14\nparameters = { depth = 3 }").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_thresholds_and_budgets() {
+        assert!(parse("[colors]\nfast = 20.0").is_err());
+        assert!(parse("[colors]\nfast = -1.0").is_err());
+        assert!(parse("[[puzzle]]\nyear = 2024\nday = 14\nbudget = 0.0").is_err());
+    }
+
+    #[test]
+    fn missing_files_use_the_defaults() {
+        let config = Config::read("does-not-exist.toml").unwrap();
+
+        assert!(config.years.is_empty());
+        assert_eq!("inputs", config.paths.inputs);
+    }
+}
@@ -0,0 +1,77 @@
+use std::{fmt::Display, num::ParseIntError, time::Duration};
+
+/// An error that prevents a puzzle from being solved
+#[derive(Debug)]
+pub enum Error {
+    /// The input file does not exist or cannot be read
+    MissingInput(String),
+    /// The input file exists, but its contents are not valid for the puzzle
+    Parse(ParseError),
+    /// The solver panicked, with the message of the panic
+    Panicked(String),
+    /// The solver did not finish within the timeout
+    TimedOut(Duration),
+    /// A parameter override is malformed or names a parameter the puzzle does not declare
+    InvalidParameter(String),
+    /// No puzzles are registered for the year
+    UnknownYear(u16),
+    /// No puzzle is registered for the day of the year
+    UnknownDay(u16, u8),
+    /// A day was selected without a year, and no year has a puzzle for it
+    UnregisteredDay(u8),
+    /// No puzzle is registered for any day in the range of the year
+    UnknownDays(u16, u8, u8),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingInput(file) => write!(f, "missing input {file}"),
+            Error::Parse(error) => write!(f, "parse error: {error}"),
+            Error::Panicked(message) => write!(f, "panicked: {message}"),
+            Error::TimedOut(timeout) => write!(f, "timed out after {}s", timeout.as_secs_f64()),
+            Error::InvalidParameter(message) => write!(f, "invalid parameter: {message}"),
+            Error::UnknownYear(year) => write!(f, "Unknown year {year}"),
+            Error::UnknownDay(year, day) => write!(f, "Unknown day {day} for year {year}"),
+            Error::UnknownDays(year, first, last) => write!(f, "No days between {first} and {last} for year {year}"),
+            Error::UnregisteredDay(day) => write!(f, "Unknown day {day} in all years"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+/// Describes why a puzzle input could not be parsed
+///
+/// The messages are static, so that creating an error does not allocate and
+/// `ok_or` can be used in hot parsing loops
+#[derive(Debug)]
+pub enum ParseError {
+    /// A number in the input could not be parsed
+    Number(ParseIntError),
+    /// The input does not have the expected format
+    Malformed(&'static str),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Number(error) => write!(f, "{error}"),
+            ParseError::Malformed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(value: ParseIntError) -> Self {
+        ParseError::Number(value)
+    }
+}
@@ -0,0 +1,240 @@
+use std::{fs, path::Path};
+
+use crate::{fingerprint, formatting::{Redaction, Solution}, registry, report::DayReport, solver::Options};
+
+/// The expected answers of a puzzle, as stored in its expect file.
+/// Either answer may be missing, for example while only the first part is solved,
+/// and either may be a fingerprint instead of the answer itself
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Expected {
+    /// Reads the expect file, returning `None` if there is none
+    pub fn load(path: impl AsRef<Path>) -> Option<Expected> {
+        let content = fs::read_to_string(path).ok()?;
+        let mut lines = content.lines().map(str::trim).map(|line| (!line.is_empty()).then(|| line.to_string()));
+
+        Some(Expected {
+            part_1: lines.next().flatten(),
+            part_2: lines.next().flatten(),
+        })
+    }
+
+    /// Takes the answers of the parts that were run, keeping the expected answers of the others.
+    /// An expected answer that matches keeps its form, unless fingerprinting, which stores every answer as a fingerprint,
+    /// including the plaintext answers that were already expected
+    fn updated(&self, solution: &Solution, redaction: Redaction) -> Expected {
+        let protected = |answer: Option<String>| match (redaction, answer) {
+            (Redaction::Fingerprinted(salt), Some(answer)) if !fingerprint::is_fingerprint(&answer) => Some(fingerprint::fingerprint(salt, &answer)),
+            (_, answer) => answer,
+        };
+
+        let updated = |expected: &Option<String>, answer: &Option<String>| protected(match answer {
+            None => expected.clone(),
+            Some(answer) if expected.as_deref().and_then(|expected| fingerprint::matches(expected, answer)) == Some(true) => expected.clone(),
+            Some(answer) => Some(answer.clone()),
+        });
+
+        Expected {
+            part_1: updated(&self.part_1, &solution.solution_1),
+            part_2: updated(&self.part_2, &solution.solution_2),
+        }
+    }
+
+    /// Checks whether the answers of the solution contradict a known expected answer.
+    /// A fingerprint that cannot be checked without the salt counts as a contradiction
+    fn conflicts_with(&self, solution: &Solution) -> bool {
+        let conflicts = |expected: &Option<String>, answer: &Option<String>| match (expected, answer) {
+            (Some(expected), Some(answer)) => fingerprint::matches(expected, answer) != Some(true),
+            _ => false,
+        };
+
+        conflicts(&self.part_1, &solution.solution_1) || conflicts(&self.part_2, &solution.solution_2)
+    }
+}
+
+/// What happened when the answers of a day were recorded
+#[derive(Debug, PartialEq, Eq)]
+pub enum Recorded {
+    /// The expect file was created or filled in
+    Written,
+    /// The expect file already contained exactly these answers
+    Unchanged,
+    /// The expect file contained different answers and was replaced
+    Overwritten,
+}
+
+/// Writes the answers of a day into its expect file.
+///
+/// Existing answers that differ are only replaced if `force` is set,
+/// so that a verified answer is not overwritten by a broken solution.
+/// When fingerprinting, new answers are recorded as fingerprints
+pub fn record(day: &DayReport, force: bool, redaction: Redaction) -> Result<Recorded, String> {
+    let file = day.expect_file();
+    let name = match &day.example {
+        Some(example) => format!("Example {example} of {} of {}", day.puzzle.name(), day.puzzle.year),
+        None => format!("{} of {}", day.puzzle.name(), day.puzzle.year),
+    };
+
+    let solution = day.result.as_ref()
+        .map_err(|error| format!("{name} was not recorded: {error}"))?;
+
+    let Some((recorded, answers)) = recording(Expected::load(&file), solution, force, redaction) else {
+        return Err(format!("{name} was not recorded: {file} contains different answers, use --force to overwrite them"));
+    };
+
+    if recorded == Recorded::Unchanged {
+        return Ok(recorded);
+    }
+
+    let content = format!("{}\n{}\n", answers.part_1.unwrap_or_default(), answers.part_2.unwrap_or_default());
+
+    Path::new(&file).parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&file, content))
+        .map_err(|error| format!("Could not write {file}: {error}"))?;
+
+    Ok(recorded)
+}
+
+/// Decides how the answers of the solution are recorded over the existing expected answers, together with the answers to write.
+/// Gives `None` if the existing answers contradict the solution and `force` is not set
+fn recording(existing: Option<Expected>, solution: &Solution, force: bool, redaction: Redaction) -> Option<(Recorded, Expected)> {
+    let answers = existing.as_ref().unwrap_or(&Expected::default()).updated(solution, redaction);
+
+    let recorded = match existing {
+        Some(existing) if existing == answers => Recorded::Unchanged,
+        Some(existing) if existing.conflicts_with(solution) => {
+            if !force {
+                return None;
+            }
+            Recorded::Overwritten
+        },
+        _ => Recorded::Written,
+    };
+
+    Some((recorded, answers))
+}
+
+/// Solves the puzzle with its input and all its examples and asserts that the answers match their expect files.
+/// Inputs or expect files that are missing are skipped, so the generated tests pass on a fresh checkout without inputs.
+/// Empty inputs and expect files without answers, like the placeholders of a new day, are skipped before solving,
+/// and only the parts with an expected answer are solved,
+/// as the parts of a new day panic until they are implemented. Fingerprints are skipped as well if there is no salt to check them with
+pub fn assert_expected(year: u16, day: u8) {
+    let puzzle = registry::find(year, day).expect("The puzzle is registered");
+
+    for example in std::iter::once(None).chain(puzzle.examples().into_iter().map(Some)) {
+        let example = example.as_deref();
+        let input_file = puzzle.input_file(example);
+        let expect_file = puzzle.expect_file(example);
+
+        match fs::metadata(&input_file) {
+            Err(_) => {
+                eprintln!("skipped {input_file}: the input is missing");
+                continue;
+            },
+            Ok(metadata) if metadata.len() == 0 => {
+                eprintln!("skipped {input_file}: the input is empty");
+                continue;
+            },
+            Ok(_) => {},
+        }
+
+        let Some(expected) = Expected::load(&expect_file) else {
+            eprintln!("skipped {input_file}: {expect_file} is missing");
+            continue;
+        };
+
+        if expected == Expected::default() {
+            eprintln!("skipped {input_file}: {expect_file} has no answers");
+            continue;
+        }
+
+        // Only the parts with an expected answer are run, so an unfinished part cannot hang the tests
+        let part = match (&expected.part_1, &expected.part_2) {
+            (Some(_), None) => Some(1),
+            (None, Some(_)) => Some(2),
+            _ => None,
+        };
+
+        let solution = puzzle.solve(example, &Options { part, ..Options::default() })
+            .unwrap_or_else(|error| panic!("{input_file} could not be solved: {error}"));
+
+        let parts = [(1, expected.part_1, solution.solution_1), (2, expected.part_2, solution.solution_2)];
+
+        for (part, expected, answer) in parts {
+            let (Some(expected), Some(answer)) = (expected, answer) else {
+                continue;
+            };
+
+            match fingerprint::matches(&expected, &answer) {
+                Some(matches) => assert!(matches, "wrong answer to part {part} for {input_file}: expected {expected}, got {answer}"),
+                None => eprintln!("skipped part {part} of {input_file}: {expected} cannot be checked without a salt"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected(part_1: Option<&str>, part_2: Option<&str>) -> Expected {
+        Expected { part_1: part_1.map(str::to_string), part_2: part_2.map(str::to_string) }
+    }
+
+    #[test]
+    fn records_a_new_file() {
+        let recorded = recording(None, &Solution::answered(Some("1"), Some("2")), false, Redaction::Shown);
+        assert_eq!(Some((Recorded::Written, expected(Some("1"), Some("2")))), recorded);
+    }
+
+    #[test]
+    fn keeps_matching_answers() {
+        let recorded = recording(Some(expected(Some("1"), Some("2"))), &Solution::answered(Some("1"), Some("2")), false, Redaction::Shown);
+        assert_eq!(Some((Recorded::Unchanged, expected(Some("1"), Some("2")))), recorded);
+    }
+
+    #[test]
+    fn fills_in_missing_answers() {
+        let recorded = recording(Some(expected(Some("1"), None)), &Solution::answered(Some("1"), Some("2")), false, Redaction::Shown);
+        assert_eq!(Some((Recorded::Written, expected(Some("1"), Some("2")))), recorded);
+    }
+
+    #[test]
+    fn keeps_answers_of_parts_that_were_not_run() {
+        let recorded = recording(Some(expected(Some("1"), Some("2"))), &Solution::answered(None, Some("2")), false, Redaction::Shown);
+        assert_eq!(Some((Recorded::Unchanged, expected(Some("1"), Some("2")))), recorded);
+    }
+
+    #[test]
+    fn refuses_to_overwrite_without_force() {
+        assert_eq!(None, recording(Some(expected(Some("1"), Some("2"))), &Solution::answered(Some("1"), Some("3")), false, Redaction::Shown));
+    }
+
+    #[test]
+    fn overwrites_with_force() {
+        let recorded = recording(Some(expected(Some("1"), Some("2"))), &Solution::answered(Some("1"), Some("3")), true, Redaction::Shown);
+        assert_eq!(Some((Recorded::Overwritten, expected(Some("1"), Some("3")))), recorded);
+    }
+
+    #[test]
+    fn fingerprints_expected_plaintext_answers() {
+        let salted = |answer| Some(fingerprint::fingerprint("salt", answer));
+        let recorded = recording(Some(expected(Some("1"), Some("2"))), &Solution::answered(Some("1"), None), false, Redaction::Fingerprinted("salt"));
+
+        assert_eq!(Some((Recorded::Written, Expected { part_1: salted("1"), part_2: salted("2") })), recorded);
+    }
+
+    #[test]
+    fn records_fingerprints() {
+        let (recorded, answers) = recording(None, &Solution::answered(Some("1"), None), false, Redaction::Fingerprinted("salt")).unwrap();
+
+        assert_eq!(Recorded::Written, recorded);
+        assert_eq!(Some(fingerprint::fingerprint("salt", "1")), answers.part_1);
+    }
+}
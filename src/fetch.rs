@@ -0,0 +1,164 @@
+use std::{fs, path::Path, thread, time::{Duration, Instant}};
+
+use crate::registry::Puzzle;
+
+/// The site the inputs are downloaded from, unless another base URL is given
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The file the session token is read from, if it is not given on the command line or in the environment
+pub const SESSION_FILE: &str = ".session";
+
+/// Identifies the tool to the site, as it asks automated requests to do
+const USER_AGENT: &str = concat!("aoc-solutions/", env!("CARGO_PKG_VERSION"), " (+https://github.com/Hoetty/aoc-solutions)");
+
+/// What happened to the input of a puzzle
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already present and not requested again
+    Cached,
+    /// The input was downloaded and stored
+    Downloaded,
+}
+
+/// Reads the session token from the given value or the session file
+pub fn session(session: Option<String>) -> Result<String, String> {
+    session
+        .or_else(|| fs::read_to_string(SESSION_FILE).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| format!("No session token, set AOC_SESSION or store it in {SESSION_FILE}"))
+}
+
+/// Downloads puzzle inputs, waiting at least the interval between two requests
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: String, interval: Duration) -> Fetcher {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Fetcher { agent, base_url: base_url.trim_end_matches('/').to_string(), session, interval, last_request: None }
+    }
+
+    /// Downloads the input of the puzzle, unless it is already present.
+    /// Empty inputs, like the placeholders of `new`, count as missing
+    pub fn fetch(&mut self, puzzle: &Puzzle) -> Result<Fetched, String> {
+        self.fetch_into(puzzle.year, puzzle.day, &puzzle.input_file(None))
+    }
+
+    /// Downloads the input of a day into the file, unless the file already has content.
+    /// The line break at the end of the input is not stored, so single line inputs can be used as they are
+    fn fetch_into(&mut self, year: u16, day: u8, file: &str) -> Result<Fetched, String> {
+        if fs::metadata(file).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        if let Some(wait) = self.last_request.and_then(|last| self.interval.checked_sub(last.elapsed())) {
+            thread::sleep(wait);
+        }
+
+        self.last_request = Some(Instant::now());
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        let input = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, _) => format!("Could not fetch {url}: status {status}"),
+                ureq::Error::Transport(transport) => format!("Could not fetch {url}: {transport}"),
+            })?
+            .into_string()
+            .map_err(|error| format!("Could not read {url}: {error}"))?;
+
+        Path::new(file).parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(file, input.trim_end_matches(['\r', '\n'])))
+            .map_err(|error| format!("Could not write {file}: {error}"))?;
+
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, io::{Read, Write}, net::TcpListener, process, thread::JoinHandle};
+
+    use super::*;
+
+    /// Answers a single request with the status and body, giving back the request it received
+    fn serve(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let response = format!("HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (base_url, server)
+    }
+
+    fn input_file(name: &str) -> String {
+        env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id())).join("day1.txt").display().to_string()
+    }
+
+    #[test]
+    fn downloads_inputs_without_the_trailing_line_break() {
+        let (base_url, server) = serve("200 OK", "(()(()(\n");
+        let file = input_file("download");
+
+        let fetched = Fetcher::new(&base_url, "token".to_string(), Duration::ZERO).fetch_into(2015, 1, &file);
+        let request = server.join().unwrap();
+
+        assert_eq!(Ok(Fetched::Downloaded), fetched);
+        assert_eq!("(()(()(", fs::read_to_string(&file).unwrap());
+        assert!(request.starts_with("GET /2015/day/1/input HTTP/1.1\r\n"));
+        assert!(request.to_lowercase().contains("cookie: session=token\r\n"));
+
+        fs::remove_dir_all(Path::new(&file).parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn reports_failed_requests() {
+        let (base_url, server) = serve("404 Not Found", "");
+        let file = input_file("missing");
+
+        let fetched = Fetcher::new(&base_url, "token".to_string(), Duration::ZERO).fetch_into(2015, 1, &file);
+        server.join().unwrap();
+
+        assert!(fetched.is_err_and(|error| error.ends_with("status 404")));
+        assert!(!Path::new(&file).exists());
+    }
+
+    #[test]
+    fn keeps_present_inputs() {
+        let file = input_file("cached");
+        fs::create_dir_all(Path::new(&file).parent().unwrap()).unwrap();
+        fs::write(&file, "(").unwrap();
+
+        // Nothing listens at this address, so a request would fail
+        let fetched = Fetcher::new("http://127.0.0.1:9", "token".to_string(), Duration::ZERO).fetch_into(2015, 1, &file);
+
+        assert_eq!(Ok(Fetched::Cached), fetched);
+        fs::remove_dir_all(Path::new(&file).parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,59 @@
+use std::{env, fs, sync::OnceLock};
+
+/// The file the salt is read from, unless it is set in the environment
+pub const SALT_FILE: &str = ".salt";
+
+/// The environment variable that takes precedence over the salt file
+pub const SALT_VARIABLE: &str = "AOC_SALT";
+
+/// Marks an answer in an expect file as a fingerprint
+const PREFIX: &str = "md5:";
+
+/// How many hex digits of the hash are kept
+const LENGTH: usize = 16;
+
+/// Reads the salt shared by everyone who verifies the fingerprints, once per process
+pub fn salt() -> Result<&'static str, String> {
+    static SALT: OnceLock<Option<String>> = OnceLock::new();
+
+    SALT.get_or_init(|| {
+        env::var(SALT_VARIABLE).ok()
+            .or_else(|| fs::read_to_string(SALT_FILE).ok())
+            .map(|salt| salt.trim().to_string())
+            .filter(|salt| !salt.is_empty())
+    })
+    .as_deref()
+    .ok_or_else(|| format!("No salt for fingerprints, set {SALT_VARIABLE} or store it in {SALT_FILE}"))
+}
+
+/// Hashes the answer with the salt, so it can be compared without being revealed.
+/// Without the salt, short answers cannot be recovered by hashing every candidate
+///
+/// ```
+/// use aoc::fingerprint::{fingerprint, matches};
+///
+/// let salted = fingerprint("salt", "42");
+///
+/// assert!(salted.starts_with("md5:"));
+/// assert_ne!(salted, fingerprint("pepper", "42"));
+/// assert_eq!(Some(true), matches("42", "42"));
+/// ```
+pub fn fingerprint(salt: &str, answer: &str) -> String {
+    let hash = format!("{:x}", md5::compute(format!("{salt}\n{answer}")));
+    format!("{PREFIX}{}", &hash[..LENGTH])
+}
+
+/// Checks whether the line of an expect file is a fingerprint instead of an answer
+pub fn is_fingerprint(expected: &str) -> bool {
+    expected.starts_with(PREFIX)
+}
+
+/// Checks an answer against an expected answer or fingerprint.
+/// Fingerprints cannot be checked without the salt, which gives `None`
+pub fn matches(expected: &str, answer: &str) -> Option<bool> {
+    if is_fingerprint(expected) {
+        salt().ok().map(|salt| fingerprint(salt, answer) == expected)
+    } else {
+        Some(expected == answer)
+    }
+}
@@ -1,47 +1,90 @@
-use std::{fmt::Display, fs, ops::BitAnd, time::Instant};
+use std::{borrow::Cow, fmt::Display, hint::black_box, ops::{Add, BitAnd}, time::Instant};
 use tabled::{builder::Builder, settings::{object::{Cell, Columns, Object, Row, Rows, Segment}, themes::Colorization, Alignment, Border, Color, Style}};
 
-#[macro_export]
-macro_rules! solutions {
-    ($year: expr, $day: expr) => {
-        use $crate::formatting::time;
-        use $crate::formatting::Solution;
+use serde::Serialize;
 
-        pub fn solutions() -> Solution {
-            let filename = format!("inputs/{}/day{}.txt", $year, $day);
-            let (input, input_time) = time(|| get_input(&filename));
-        
-            Solution::evaluated(
-                format!("Day {}", $day), 
-                || solve_first(&input),
-                || solve_second(&input),
-                input_time
-            )
-        }
+use crate::{allocation::{self, Allocations}, baseline::{Baseline, Delta}, config, expect::Expected, fingerprint, registry::Puzzle, report::YearReport, solver::Options};
+
+/// The answers and timings of a puzzle.
+/// Parts that were not run have no answer and take no time.
+/// The allocations are only counted on request, and never for parts that were not run
+pub struct Solution {
+    pub name: String,
+    pub input_time: Timing,
+    pub input_memory: Option<Allocations>,
+    pub solution_1: Option<String>,
+    pub time_1: Timing,
+    pub memory_1: Option<Allocations>,
+    pub solution_2: Option<String>,
+    pub time_2: Timing,
+    pub memory_2: Option<Allocations>,
+}
+
+/// Summarizes the time a step took over one or more runs, in nanoseconds
+#[derive(Clone, Copy, Default, Debug, Serialize)]
+pub struct Timing {
+    pub min: u128,
+    pub median: u128,
+    pub mean: u128,
+    pub stddev: u128,
+}
+
+impl Timing {
+    /// The timing of a step that was only measured once
+    pub fn single(time: u128) -> Timing {
+        Timing { min: time, median: time, mean: time, stddev: 0 }
+    }
+
+    /// Calculates the statistics over multiple measurements
+    pub fn from_samples(mut samples: Vec<u128>) -> Timing {
+        samples.sort_unstable();
+
+        let count = samples.len() as u128;
+        let middle = samples.len() / 2;
+
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        let mean = samples.iter().sum::<u128>() / count;
+        let variance = samples.iter().map(|&sample| (sample.abs_diff(mean) as f64).powi(2)).sum::<f64>() / count as f64;
+
+        Timing { min: samples[0], median, mean, stddev: variance.sqrt() as u128 }
     }
 }
 
-pub struct Solution {
-    name: String,
-    input_time: u128,
-    solution_1: String,
-    time_1: u128,
-    solution_2: String,
-    time_2: u128,
+impl Add for Timing {
+    type Output = Timing;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Timing {
+            min: self.min + rhs.min,
+            median: self.median + rhs.median,
+            mean: self.mean + rhs.mean,
+            stddev: (((self.stddev * self.stddev) + (rhs.stddev * rhs.stddev)) as f64).sqrt() as u128,
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum TestResult {
     Success,
     Failure,
-    Unknown
+    Unknown,
+    NotRun
 }
 
 impl BitAnd for TestResult {
     type Output = TestResult;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        if self == TestResult::Unknown || rhs == TestResult::Unknown {
+        if self == TestResult::NotRun {
+            rhs
+        } else if rhs == TestResult::NotRun {
+            self
+        } else if self == TestResult::Unknown || rhs == TestResult::Unknown {
             TestResult::Unknown
         } else if self == TestResult::Failure || rhs == TestResult::Failure {
             TestResult::Failure
@@ -53,47 +96,179 @@ impl BitAnd for TestResult {
 
 impl Solution {
 
-    pub fn evaluated<S: Display, T: Display, F, G>(name: String, first: F, second: G, input_time: u128) -> Solution where F: FnOnce() -> S, G: FnOnce() -> T {
-        let (first, time_first) = time(first);
-        let (second, time_second) = time(second);
+    /// Solves the given parts, either timing them once or benchmarking them over the given number of runs
+    pub fn evaluated<S: Display, T: Display, F, G>(name: String, first: Option<F>, second: Option<G>, input: (Timing, Option<Allocations>), options: &Options) -> Solution where F: FnMut() -> S, G: FnMut() -> T {
+        let (solution_1, time_1, memory_1) = measure_part(first, options);
+        let (solution_2, time_2, memory_2) = measure_part(second, options);
+
+        Solution {
+            name,
+            input_time: input.0,
+            input_memory: input.1,
+            solution_1,
+            time_1,
+            memory_1,
+            solution_2,
+            time_2,
+            memory_2,
+        }
+    }
 
-        Solution { name, input_time, solution_1: first.to_string(), time_1: time_first, solution_2: second.to_string(), time_2: time_second }
+    /// The time of the input parsing and all parts that were run
+    pub fn total_time(&self) -> Timing {
+        self.input_time + self.time_1 + self.time_2
     }
 
-    pub fn test(&self, year: &str) -> (TestResult, TestResult) {
-        let filename = self.name.to_lowercase().replace(" ", "");
-        let Ok(file) = fs::read_to_string(format!("./expect/{year}/{filename}.txt")) else {
-            return (TestResult::Unknown, TestResult::Unknown);
-        };
+    /// The allocations of the input parsing and all parts that were run, if they were counted
+    pub fn total_memory(&self) -> Option<Allocations> {
+        self.input_memory.map(|input| [self.memory_1, self.memory_2].into_iter().flatten().fold(input, Add::add))
+    }
+
+    pub fn test(&self, expect_file: &str) -> (TestResult, TestResult) {
+        let expected = Expected::load(expect_file).unwrap_or_default();
+
+        (test_answer(self.solution_1.as_deref(), expected.part_1), test_answer(self.solution_2.as_deref(), expected.part_2))
+    }
 
-        let (first, second) = file.split_once("\n").unwrap_or_else(|| panic!("./expect/{year}/{filename}.txt: Please keep your expected inputs in the format <solution1><newline><solution2>"));
+}
 
-        (
-            if self.solution_1 == first { TestResult::Success } else { TestResult::Failure }, 
-            if self.solution_2 == second { TestResult::Success } else { TestResult::Failure }
-        )
+#[cfg(test)]
+impl Solution {
+    /// A solution with the answers, but without timings or allocations. Parts without an answer were not run
+    pub fn answered(part_1: Option<&str>, part_2: Option<&str>) -> Solution {
+        Solution {
+            name: "Day 1".to_string(),
+            input_time: Timing::default(),
+            input_memory: None,
+            solution_1: part_1.map(str::to_string),
+            time_1: Timing::default(),
+            memory_1: None,
+            solution_2: part_2.map(str::to_string),
+            time_2: Timing::default(),
+            memory_2: None,
+        }
     }
+}
 
+/// Measures a part, if it is run
+fn measure_part<T: Display, F>(part: Option<F>, options: &Options) -> (Option<String>, Timing, Option<Allocations>) where F: FnMut() -> T {
+    match part {
+        Some(part) => {
+            let (answer, time, memory) = measure(part, options.bench, options.memory);
+            (Some(answer.to_string()), time, memory)
+        },
+        None => (None, Timing::default(), None),
+    }
 }
 
+/// Compares an answer against the expected answer, if the part was run and an answer is known
+fn test_answer(answer: Option<&str>, expected: Option<String>) -> TestResult {
+    match (answer, expected) {
+        (None, _) => TestResult::NotRun,
+        (Some(answer), Some(expected)) => match fingerprint::matches(&expected, answer) {
+            Some(true) => TestResult::Success,
+            Some(false) => TestResult::Failure,
+            None => TestResult::Unknown,
+        },
+        (Some(_), None) => TestResult::Unknown,
+    }
+}
+
+/// Measures the time a function takes in nanoseconds
 pub fn time<T, F>(function: F) -> (T, u128) where F: FnOnce() -> T {
     let start = Instant::now();
     let value = function();
-    let elapsed = start.elapsed().as_micros();
+    let elapsed = start.elapsed().as_nanos();
     (value, elapsed)
 }
 
+/// Runs the function repeatedly and collects statistics over the runs.
+///
+/// The value of the first run is returned, so answers are only checked once.
+/// The first runs also serve as warmup and are not part of the statistics
+pub fn benchmark<T, F>(mut function: F, runs: u32) -> (T, Timing) where F: FnMut() -> T {
+    let value = function();
+
+    for _ in 1..(runs / 10).max(1) {
+        black_box(function());
+    }
+
+    let samples = (0..runs.max(1)).map(|_| time(|| black_box(function())).1).collect();
+
+    (value, Timing::from_samples(samples))
+}
+
+/// Times the function once or benchmarks it, if a number of runs is given.
+/// When requested, the allocations are counted in a separate run beforehand, so counting does not slow down the timed runs
+pub fn measure<T, F>(mut function: F, bench: Option<u32>, memory: bool) -> (T, Timing, Option<Allocations>) where F: FnMut() -> T {
+    let allocations = memory.then(|| allocation::track(&mut function).1);
+
+    let (value, timing) = match bench {
+        Some(runs) => benchmark(&mut function, runs),
+        None => {
+            let (value, elapsed) = time(&mut function);
+            (value, Timing::single(elapsed))
+        }
+    };
+
+    (value, timing, allocations)
+}
+
 pub fn format_time(time: u128) -> String {
-    format!("{:.3}ms", time as f64 / 1000.0)
+    format!("{:.3}ms", time as f64 / 1_000_000.0)
+}
+
+/// Formats a time with nanosecond resolution, used for benchmarks
+pub fn format_precise_time(time: u128) -> String {
+    format!("{:.6}ms", time as f64 / 1_000_000.0)
+}
+
+/// Formats a number of bytes with a binary unit
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.2}{}", UNITS[unit])
+    }
 }
 
 pub fn format_percentage(time: u128, total: u128) -> String {
+    if total == 0 {
+        return format!("{:.2}%", 0.0);
+    }
+
     format!("{:.2}%", time as f64 / total as f64 * 100.0)
 }
 
+/// How answers are shown in the output
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum Redaction {
+    /// Show the answers
+    #[default]
+    Shown,
+    /// Hide the answers
+    Hidden,
+    /// Show a fingerprint of each answer with this salt, which hides the answer but can be compared
+    Fingerprinted(&'static str),
+}
+
 #[inline(always)]
-pub fn format_solution(solution: &str, redact: bool) -> &str {
-    if redact { "######" } else { solution }
+pub fn format_solution(solution: &str, redaction: Redaction) -> Cow<'_, str> {
+    match redaction {
+        Redaction::Shown => Cow::Borrowed(solution),
+        Redaction::Hidden => Cow::Borrowed("######"),
+        Redaction::Fingerprinted(salt) => Cow::Owned(fingerprint::fingerprint(salt, solution)),
+    }
 }
 
 pub fn format_test(passed: TestResult) -> String {
@@ -101,69 +276,271 @@ pub fn format_test(passed: TestResult) -> String {
         "✔".to_string()
     } else if passed == TestResult::Failure {
         "✘".to_string()
+    } else if passed == TestResult::NotRun {
+        "-".to_string()
     } else {
         "?".to_string()
     }
 }
 
+/// Checks whether a test result should be highlighted as a problem
+fn is_problem(passed: TestResult) -> bool {
+    passed == TestResult::Failure || passed == TestResult::Unknown
+}
+
+/// Colors a time by the thresholds of the configuration
 pub fn time_color(time: u128) -> Color {
-    if time < 1000 {
+    let colors = &config::get().colors;
+
+    if time < config::milliseconds(colors.fast) {
         Color::FG_BRIGHT_GREEN
-    } else if time < 10000 {
+    } else if time < config::milliseconds(colors.slow) {
         Color::FG_BRIGHT_YELLOW
     } else {
         Color::FG_BRIGHT_RED
     }
 }
 
-pub fn year(name: &str, solutions: Vec<Solution>, redact: bool) -> String {
-    let total_time: u128 = solutions.iter().map(|s| s.time_1 + s.time_2 + s.input_time).sum::<u128>();
+/// Controls what the year table shows
+#[derive(Default)]
+pub struct TableOptions<'a> {
+    /// How the answers are shown
+    pub redaction: Redaction,
+    /// Show the statistics of a benchmark
+    pub bench: bool,
+    /// Show the allocations of each step
+    pub memory: bool,
+    /// Show the difference to the timings of a baseline
+    pub baseline: Option<&'a Baseline>,
+    /// Regressions above this percentage are highlighted
+    pub regression_threshold: f64,
+    /// Highlight the answers that changed since these reports, used when watching
+    pub previous: Option<&'a [YearReport]>,
+}
+
+/// Builds a table row from the leading cells and the time columns
+fn row(cells: [&str; 4], times: Vec<String>) -> Vec<String> {
+    cells.into_iter().map(String::from).chain(times).collect()
+}
+
+/// Formats the difference of a time to its baseline, colored by whether it improved or regressed
+fn delta_column(time: u128, baseline: u128, options: &TableOptions) -> (String, Color) {
+    let delta = Delta::new(time, baseline);
+    let sign = if delta.absolute < 0 { "-" } else { "+" };
+
+    let absolute = if options.bench {
+        format_precise_time(delta.absolute.unsigned_abs())
+    } else {
+        format_time(delta.absolute.unsigned_abs())
+    };
+
+    let color = if delta.absolute < 0 {
+        Color::FG_BRIGHT_GREEN
+    } else if delta.is_regression(options.regression_threshold) {
+        Color::FG_BRIGHT_RED
+    } else {
+        Color::FG_BRIGHT_YELLOW
+    };
+
+    (format!("{sign}{absolute} ({}{:.2}%)", if delta.percentage < 0.0 { "" } else { "+" }, delta.percentage), color)
+}
+
+/// Formats the time columns of a row. Benchmarks additionally show the minimum, mean and standard deviation
+fn time_columns(timing: Timing, total: u128, bench: bool) -> Vec<String> {
+    if bench {
+        vec![
+            format_precise_time(timing.median),
+            format_percentage(timing.median, total),
+            format_precise_time(timing.min),
+            format_precise_time(timing.mean),
+            format!("±{}", format_precise_time(timing.stddev)),
+        ]
+    } else {
+        vec![format_time(timing.median), format_percentage(timing.median, total)]
+    }
+}
+
+/// Formats the allocation columns of a row, which are empty if the allocations were not counted
+fn memory_columns(allocations: Option<Allocations>) -> Vec<String> {
+    match allocations {
+        Some(allocations) => vec![allocations.count.to_string(), format_bytes(allocations.bytes), format_bytes(allocations.peak)],
+        None => vec![],
+    }
+}
+
+/// Appends the allocation columns after the time columns
+fn push_memory_columns(row: &mut Vec<String>, allocations: Option<Allocations>, last_time_column: usize) {
+    row.resize(last_time_column + 1, String::new());
+    row.extend(memory_columns(allocations));
+}
+
+/// Renders the table for a year
+pub fn year(report: &YearReport, options: &TableOptions) -> String {
+    let TableOptions { redaction, bench, memory, .. } = *options;
+    let name = &report.year.to_string();
+    // Examples are regression tests, so only the real inputs count towards the total
+    let total = report.days.iter()
+        .filter(|day| day.example.is_none())
+        .flat_map(|day| &day.result)
+        .fold(Timing::default(), |total, s| total + s.total_time());
+    let total_time = total.median;
+    let total_memory = report.days.iter()
+        .filter(|day| day.example.is_none())
+        .flat_map(|day| &day.result)
+        .flat_map(|solution| solution.total_memory())
+        .reduce(Add::add);
 
     let mut builder = Builder::default();
 
-    builder.push_record(["", "", &format!("Year {name}")]);
+    let last_time_column = if bench { 8 } else { 5 };
+    let last_memory_column = if memory { last_time_column + 3 } else { last_time_column };
+    let delta_column_index = last_memory_column + 1;
+
+    let mut header = vec![String::new(), String::new(), format!("Year {name}")];
+
+    if bench {
+        header.extend(["", "median", "", "min", "mean", "stddev"].map(String::from));
+    }
+
+    if memory {
+        header.resize(last_time_column + 1, String::new());
+        header.extend(["allocs", "allocated", "peak"].map(String::from));
+    }
+
+    if options.baseline.is_some() {
+        header.resize(delta_column_index, String::new());
+        header.push("delta".to_string());
+    }
+
+    builder.push_record(header);
 
     let mut passed_all = TestResult::Success;
     let mut failed: Vec<Cell> = vec![];
     let mut row_colors: Vec<(Row, Color)> = vec![];
+    let mut delta_colors: Vec<(Cell, Color)> = vec![];
+    let mut changed: Vec<Cell> = vec![];
 
     let mut i = 1;
 
-    for solution in &solutions {
-        let (passed_1, passed_2) = solution.test(name);
+    for day in &report.days {
+        let solution = match &day.result {
+            Ok(solution) => solution,
+            Err(error) => {
+                passed_all = passed_all & TestResult::Failure;
+
+                failed.push(Cell::new(i, 2));
+                failed.push(Cell::new(i, 3));
+
+                builder.push_record([&day.name(), "", &error.to_string(), &format_test(TestResult::Failure)]);
+                builder.push_record([""]);
+
+                i += 2;
+                continue;
+            }
+        };
+
+        let (passed_1, passed_2) = solution.test(&day.expect_file());
         let passed = passed_1 & passed_2;
         passed_all = passed_all & passed;
 
-        if passed_1 != TestResult::Success {
+        if is_problem(passed_1) {
             failed.push(Cell::new(i + 1, 3));
         }
 
-        if passed_2 != TestResult::Success {
+        if is_problem(passed_2) {
             failed.push(Cell::new(i + 2, 3));
         }
 
-        if passed != TestResult::Success {
+        if is_problem(passed) {
             failed.push(Cell::new(i + 3, 3));
         }
 
-        builder.push_record([&solution.name, "I", "", "", &format_time(solution.input_time), &format_percentage(solution.input_time, total_time)]);
-        builder.push_record(["", "#1", format_solution(&solution.solution_1, redact), &format_test(passed_1) , &format_time(solution.time_1), &format_percentage(solution.time_1, total_time)]);
-        builder.push_record(["", "#2", format_solution(&solution.solution_2, redact), &format_test(passed_2), &format_time(solution.time_2), &format_percentage(solution.time_2, total_time)]);
-        builder.push_record(["", "", "", &format_test(passed), &format_time(solution.time_1 + solution.time_2 + solution.input_time), &format_percentage(solution.time_1 + solution.time_2 + solution.input_time, total_time)]);
+        let previous = options.previous
+            .and_then(|previous| previous.iter().find(|previous| previous.year == report.year))
+            .and_then(|previous| previous.days.iter().find(|previous| previous.puzzle.day == day.puzzle.day && previous.example == day.example))
+            .map(|previous| previous.result.as_ref().ok());
+
+        if let Some(previous) = previous {
+            if previous.is_none_or(|previous| previous.solution_1 != solution.solution_1) {
+                changed.push(Cell::new(i + 1, 2));
+            }
+
+            if previous.is_none_or(|previous| previous.solution_2 != solution.solution_2) {
+                changed.push(Cell::new(i + 2, 2));
+            }
+        }
+
+        let solution_time = solution.total_time();
+        // Examples do not count towards the total, so their percentages are shares of the example itself
+        let share_of = if day.example.is_some() { solution_time.median } else { total_time };
+
+        let part_row = |part: &str, answer: &Option<String>, passed: TestResult, time: Timing| match answer {
+            Some(answer) => row(["", part, &format_solution(answer, redaction), &format_test(passed)], time_columns(time, share_of, bench)),
+            None => row(["", part, "not run", &format_test(passed)], vec![]),
+        };
+
+        let mut rows = [
+            row([&solution.name, "I", "", ""], time_columns(solution.input_time, share_of, bench)),
+            part_row("#1", &solution.solution_1, passed_1, solution.time_1),
+            part_row("#2", &solution.solution_2, passed_2, solution.time_2),
+            row(["", "", "", &format_test(passed)], time_columns(solution_time, share_of, bench)),
+        ];
+
+        if let Some((_, budget)) = day.over_budget() {
+            rows[3][2] = format!("over budget of {}", format_time(budget));
+            failed.push(Cell::new(i + 3, 2));
+        }
+
+        if memory {
+            let allocations = [solution.input_memory, solution.memory_1, solution.memory_2, solution.total_memory()];
+
+            for (row, allocations) in rows.iter_mut().zip(allocations) {
+                push_memory_columns(row, allocations, last_time_column);
+            }
+        }
+
+        let baseline = options.baseline
+            .filter(|_| day.example.is_none())
+            .and_then(|baseline| baseline.get(report.year, day.puzzle.day));
+
+        if let Some(timings) = baseline {
+            for (offset, (row, comparison)) in rows.iter_mut().zip(timings.compare(solution)).enumerate() {
+                let Some((time, baseline)) = comparison else {
+                    continue;
+                };
+
+                let (delta, color) = delta_column(time, baseline, options);
+
+                row.resize(delta_column_index, String::new());
+                row.push(delta);
+                delta_colors.push((Cell::new(i + offset, delta_column_index), color));
+            }
+        }
+
+        for row in rows {
+            builder.push_record(row);
+        }
+
         builder.push_record([""]);
 
-        row_colors.push((Rows::single(i), time_color(solution.input_time)));
-        row_colors.push((Rows::single(i + 1), time_color(solution.time_1)));
-        row_colors.push((Rows::single(i + 2), time_color(solution.time_2)));
-        row_colors.push((Rows::single(i + 3), time_color(solution.time_1 + solution.time_2 + solution.input_time)));
+        row_colors.push((Rows::single(i), time_color(solution.input_time.median)));
+        row_colors.push((Rows::single(i + 1), time_color(solution.time_1.median)));
+        row_colors.push((Rows::single(i + 2), time_color(solution.time_2.median)));
+        row_colors.push((Rows::single(i + 3), time_color(solution_time.median)));
 
         i += 5;
     }
 
-    builder.push_record(["Total", "", "", &format_test(passed_all), &format_time(total_time), &format_percentage(total_time, total_time)]);
+    let mut total_row = row(["Total", "", "", &format_test(passed_all)], time_columns(total, total_time, bench));
+
+    if memory {
+        push_memory_columns(&mut total_row, total_memory, last_time_column);
+    }
+
+    builder.push_record(total_row);
     row_colors.push((Rows::single(i), time_color(total_time)));
 
-    if passed_all != TestResult::Success {
+    if is_problem(passed_all) {
         failed.push(Cell::new(i, 3));
     }
 
@@ -175,7 +552,7 @@ pub fn year(name: &str, solutions: Vec<Solution>, redact: bool) -> String {
         .modify(Rows::last(), Border::inherit(Style::re_structured_text()).top('='));
 
     for colorization in row_colors {
-        table.with(Colorization::exact([colorization.1], colorization.0.intersect(Columns::new(4..=5))));
+        table.with(Colorization::exact([colorization.1], colorization.0.intersect(Columns::new(4..=last_time_column))));
     }
         
     table.with(Colorization::exact([Color::FG_BRIGHT_GREEN], Columns::single(3)));
@@ -184,5 +561,38 @@ pub fn year(name: &str, solutions: Vec<Solution>, redact: bool) -> String {
         table.with(Colorization::exact([Color::FG_BRIGHT_RED], cell));
     }
 
+    for (cell, color) in delta_colors {
+        table.with(Colorization::exact([color], cell));
+    }
+
+    for cell in changed {
+        table.with(Colorization::exact([Color::FG_BRIGHT_CYAN], cell));
+    }
+
+    if report.parallel() {
+        format!("{table}\nTimed with {} days solved in parallel, so the timings include the load of the other days", report.jobs)
+    } else {
+        table.to_string()
+    }
+}
+
+pub fn catalogue(puzzles: &[&Puzzle]) -> String {
+    let mut builder = Builder::default();
+
+    builder.push_record(["Year", "Day", "Title", "Parameters"]);
+
+    for puzzle in puzzles {
+        let parameters = puzzle.parameters.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+        builder.push_record([puzzle.year.to_string(), puzzle.day.to_string(), puzzle.title.to_string(), parameters]);
+    }
+
+    builder.push_record(["Total", &puzzles.len().to_string(), "", ""]);
+
+    let mut table = builder.build();
+    table
+        .with(Style::re_structured_text())
+        .modify(Columns::new(0..=1), Alignment::right())
+        .modify(Rows::last(), Border::inherit(Style::re_structured_text()).top('='));
+
     table.to_string()
-}
\ No newline at end of file
+}
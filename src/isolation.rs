@@ -0,0 +1,107 @@
+use std::{any::Any, panic::{self, AssertUnwindSafe}, sync::{atomic::{AtomicUsize, Ordering}, mpsc, Mutex, Once}, thread};
+
+use crate::{error::Error, formatting::Solution, registry::Puzzle, solver::Options};
+
+/// Solver threads are named with this prefix, so their panics can be told apart from panics of the runner
+const THREAD_PREFIX: &str = "solver ";
+
+/// Solvers run on their own thread, which gets a large stack so that recursive solutions behave like on the main thread
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+static QUIET_PANICS: Once = Once::new();
+
+/// Keeps panics of solver threads from printing to stderr, as they are reported in the table instead.
+/// Panics anywhere else are still printed as usual
+fn quiet_solver_panics() {
+    QUIET_PANICS.call_once(|| {
+        let default = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !thread::current().name().is_some_and(|name| name.starts_with(THREAD_PREFIX)) {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Extracts the message a panic was raised with
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Solves the puzzle on its own thread, so that a panic or an endless loop does not take down the whole run.
+///
+/// A panic is reported as [`Error::Panicked`]. If the timeout of the options passes first, [`Error::TimedOut`] is reported
+/// and the thread is abandoned. It cannot be stopped, so it keeps running in the background until the program exits
+pub fn solve(puzzle: &'static Puzzle, example: Option<String>, options: Options) -> Result<Solution, Error> {
+    quiet_solver_panics();
+
+    let (sender, receiver) = mpsc::channel();
+    let timeout = options.timeout;
+
+    let spawned = thread::Builder::new()
+        .name(format!("{THREAD_PREFIX}{} {} {}", puzzle.year, puzzle.day, example.as_deref().unwrap_or("input")))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(example.as_deref(), &options)))
+                .unwrap_or_else(|payload| Err(Error::Panicked(panic_message(payload))));
+
+            // The runner stops listening once the timeout passed, so the result may have no receiver
+            sender.send(result).ok();
+        });
+
+    if let Err(error) = spawned {
+        return Err(Error::Panicked(format!("could not start solver thread: {error}")));
+    }
+
+    match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).unwrap_or(Err(Error::TimedOut(timeout))),
+        None => receiver.recv().unwrap_or_else(|_| Err(Error::Panicked("solver thread stopped without a result".to_string()))),
+    }
+}
+
+/// How many puzzles are solved at the same time when asked for `jobs`, which is never more than there are puzzles
+///
+/// ```
+/// use aoc::isolation::workers;
+///
+/// assert_eq!(1, workers(4, 1));
+/// assert_eq!(4, workers(4, 25));
+/// assert_eq!(1, workers(0, 25));
+/// ```
+pub fn workers(jobs: usize, puzzles: usize) -> usize {
+    jobs.clamp(1, puzzles.max(1))
+}
+
+/// Solves all puzzles with their input or a named example in isolation, running up to `jobs` of them at the same time.
+/// The results are in the order of the puzzles, no matter in which order they finish
+pub fn solve_all(puzzles: &[(&'static Puzzle, Option<String>)], options: &Options, jobs: usize) -> Vec<Result<Solution, Error>> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<Result<Solution, Error>>>> = puzzles.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..workers(jobs, puzzles.len()) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some((puzzle, example)) = puzzles.get(index) else {
+                        break;
+                    };
+
+                    *results[index].lock().expect("Only this worker writes the result") = Some(solve(puzzle, example.clone(), options.clone()));
+                }
+            });
+        }
+    });
+
+    results.into_iter()
+        .map(|result| result.into_inner().expect("All workers finished").expect("Every puzzle was solved"))
+        .collect()
+}
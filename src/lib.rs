@@ -0,0 +1,20 @@
+pub mod y2015;
+pub mod y2024;
+
+pub mod allocation;
+pub mod args;
+pub mod baseline;
+pub mod config;
+pub mod error;
+pub mod expect;
+pub mod fetch;
+pub mod fingerprint;
+pub mod formatting;
+pub mod isolation;
+pub mod parameters;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod solver;
+pub mod util;
+pub mod watch;
@@ -1,27 +1,176 @@
-use std::fs;
-use args::Args;
+use std::{fs, process::ExitCode};
+use aoc::{allocation, args::{self, Args, Command}, baseline::Baseline, config, expect::{self, Recorded}, fetch::{self, Fetched, Fetcher}, formatting::{catalogue, format_time, year, TableOptions}, registry, report::{self, Format}, scaffold, watch};
 use clap::Parser;
 use strip_ansi_escapes::strip;
 
-mod y2015;
-mod y2024;
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: allocation::CountingAllocator = allocation::CountingAllocator;
 
-pub mod args;
-pub mod formatting;
-pub mod util;
-
-fn main() {
+fn main() -> ExitCode {
     let args = Args::parse();
 
-    let years = args.years();
+    let config = match config::load(&args.config) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Some(Command::New { year, day, title }) = &args.command {
+        return match scaffold::new_day(*year, *day, title) {
+            Ok(changed) => {
+                for file in changed {
+                    println!("{file}");
+                }
+                ExitCode::SUCCESS
+            },
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if let Some(Command::Fetch { year, day, selection, session, base_url, interval }) = &args.command {
+        let (selection, session) = match (args::select(year, day, selection), fetch::session(session.clone())) {
+            (Ok(selection), Ok(session)) => (selection, session),
+            (Err(error), _) => {
+                eprintln!("{error}");
+                return ExitCode::from(2);
+            },
+            (_, Err(error)) => {
+                eprintln!("{error}");
+                return ExitCode::from(2);
+            }
+        };
+
+        let mut fetcher = Fetcher::new(base_url, session, *interval);
+        let mut failed = false;
+
+        for puzzle in selection.into_iter().flat_map(|(_, puzzles)| puzzles) {
+            match fetcher.fetch(puzzle) {
+                Ok(Fetched::Cached) => {},
+                Ok(Fetched::Downloaded) => println!("{}", puzzle.input_file(None)),
+                Err(error) => {
+                    eprintln!("{error}");
+                    failed = true;
+                }
+            }
+        }
+
+        return if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+    }
+
+    if args.list {
+        println!("{}", catalogue(&registry::puzzles()));
+        return ExitCode::SUCCESS;
+    }
+
+    let redaction = match args.redaction() {
+        Ok(redaction) => redaction,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.memory && !allocation::AVAILABLE {
+        eprintln!("Counting memory needs the memory feature, build the runner with --features memory");
+        return ExitCode::from(2);
+    }
+
+    let baseline = match args.baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.watch {
+        return watch::run(&args, baseline.as_ref(), redaction);
+    }
 
-    let output = years.join("\n\n");
+    let years = match args.years() {
+        Ok(years) => years,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    };
 
-    fs::remove_file("output.txt").unwrap_or(());
+    let mut record_failed = false;
+
+    if args.record {
+        for day in years.iter().flat_map(|year| &year.days) {
+            match expect::record(day, args.force, redaction) {
+                Ok(Recorded::Unchanged) => {},
+                Ok(Recorded::Written) => eprintln!("Recorded {}", day.expect_file()),
+                Ok(Recorded::Overwritten) => eprintln!("Overwrote {}", day.expect_file()),
+                Err(error) => {
+                    eprintln!("{error}");
+                    record_failed = true;
+                }
+            }
+        }
+    }
+
+    let any_failed = record_failed || years.iter().any(|year| year.failed());
+
+    let table_options = TableOptions {
+        redaction,
+        bench: args.bench.is_some(),
+        memory: args.memory,
+        baseline: baseline.as_ref(),
+        regression_threshold: args.regression_threshold,
+        ..TableOptions::default()
+    };
+
+    let output = match args.format {
+        Format::Table => years.iter()
+            .map(|report| year(report, &table_options))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        Format::Json => report::json(&years, redaction),
+        Format::Csv => report::csv(&years, redaction),
+    };
+
+    fs::remove_file(&config.paths.output).unwrap_or(());
 
     println!("{output}");
     if args.output {
-        fs::write("output.txt", strip(output)).unwrap_or(());
+        fs::write(&config.paths.output, strip(output)).unwrap_or(());
     }
 
+    let regressions = baseline
+        .map(|baseline| baseline.regressions(&years, args.regression_threshold))
+        .unwrap_or_default();
+
+    for (year, day, delta) in &regressions {
+        eprintln!("Day {day} of {year} regressed by {:.2}%", delta.percentage);
+    }
+
+    let over_budget: Vec<_> = years.iter()
+        .flat_map(|year| &year.days)
+        .filter_map(|day| Some((day, day.over_budget()?)))
+        .collect();
+
+    for (day, (time, budget)) in &over_budget {
+        eprintln!("{} of {} took {}, over its budget of {}", day.puzzle.name(), day.puzzle.year, format_time(*time), format_time(*budget));
+    }
+
+    if let Some(name) = &args.save_baseline {
+        if let Err(error) = Baseline::save(name, &years) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if any_failed || !regressions.is_empty() || !over_budget.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
@@ -0,0 +1,91 @@
+use std::{fmt::Display, fs};
+
+use crate::error::{Error, ParseError};
+
+/// A named parameter a puzzle declares in `solutions!`, with the value used for the real puzzle input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub default: i64,
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.default)
+    }
+}
+
+/// The values of the parameters a puzzle is solved with.
+///
+/// Puzzles bake some properties of the real input into their code, like the size of a grid.
+/// The official examples are smaller, so these properties are declared as parameters,
+/// which start out with their defaults and can be overridden for an input
+///
+/// ```
+/// use aoc::parameters::{Parameter, Parameters};
+///
+/// let mut parameters = Parameters::defaults(&[Parameter { name: "width", default: 101 }]);
+/// parameters.set("width", 11).unwrap();
+///
+/// let width: usize = parameters.get("width").unwrap();
+/// assert_eq!(11, width);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Parameters {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Parameters {
+    /// Starts out with the defaults of the declared parameters
+    pub fn defaults(declared: &[Parameter]) -> Parameters {
+        Parameters { values: declared.iter().map(|parameter| (parameter.name, parameter.default)).collect() }
+    }
+
+    /// Overrides a declared parameter, failing if the puzzle does not declare it
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), String> {
+        let (_, current) = self.values.iter_mut()
+            .find(|(declared, _)| *declared == name)
+            .ok_or_else(|| format!("{name} is not a parameter of the puzzle"))?;
+
+        *current = value;
+        Ok(())
+    }
+
+    /// Checks whether the puzzle declares the parameter
+    pub fn declares(&self, name: &str) -> bool {
+        self.values.iter().any(|(declared, _)| *declared == name)
+    }
+
+    /// Reads a parameter, failing if it is not declared or does not fit into the type.
+    /// This happens while parsing, so an override that makes no sense for the puzzle shows up as a parse error
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, ParseError> {
+        let (_, value) = self.values.iter()
+            .find(|(declared, _)| *declared == name)
+            .ok_or(ParseError::Malformed("The parameter is not declared by the puzzle"))?;
+
+        T::try_from(*value).map_err(|_| ParseError::Malformed("The parameter is out of range"))
+    }
+
+    /// Overrides parameters from a file with one `name = value` per line.
+    /// Empty lines and lines starting with `#` are ignored
+    pub fn load(&mut self, file: &str) -> Result<(), Error> {
+        let Ok(content) = fs::read_to_string(file) else {
+            return Ok(());
+        };
+
+        for line in content.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (name, value) = parse_assignment(line).map_err(|error| Error::InvalidParameter(format!("{file}: {error}")))?;
+            self.set(&name, value).map_err(|error| Error::InvalidParameter(format!("{file}: {error}")))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a parameter assignment of the form `name=value`, as used on the command line and in parameter files
+pub fn parse_assignment(assignment: &str) -> Result<(String, i64), String> {
+    let (name, value) = assignment.split_once('=').ok_or_else(|| format!("{assignment} is not of the form name=value"))?;
+    let value = value.trim().parse().map_err(|_| format!("{} is not a whole number", value.trim()))?;
+
+    Ok((name.trim().to_string(), value))
+}
@@ -0,0 +1,174 @@
+use std::fs;
+
+use crate::{config, error::{Error, ParseError}, formatting::Solution, parameters::{Parameter, Parameters}, solver::{Options, ParsedInput}};
+
+/// Registers a solver for the puzzle of the given year and day.
+///
+/// Properties of the real input that the solver relies on can be declared as named parameters with their default.
+/// They are available to the solver as `PARAMETERS` and can be overridden to solve the smaller examples.
+///
+/// Every registered puzzle also gets a test, which checks the answers for its input and examples against their expect files
+///
+/// ```ignore
+/// solutions!{2024, 1, "Historian Hysteria", Day1}
+/// solutions!{2024, 14, "Restroom Redoubt", Day14, width = 101, height = 103}
+/// ```
+#[macro_export]
+macro_rules! solutions {
+    ($year: expr, $day: expr, $title: expr, $solver: ty $(, $name: ident = $default: expr)* $(,)?) => {
+        impl $solver {
+            /// The parameters declared for the puzzle, with the values for the real input
+            pub const PARAMETERS: &'static [$crate::parameters::Parameter] = &[
+                $($crate::parameters::Parameter { name: stringify!($name), default: $default }),*
+            ];
+        }
+
+        inventory::submit! {
+            $crate::registry::Puzzle {
+                year: $year,
+                day: $day,
+                title: $title,
+                parameters: <$solver>::PARAMETERS,
+                solver: $crate::solver::evaluate::<$solver>,
+                prepare: $crate::solver::prepare::<$solver>,
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn expected_answers() {
+            $crate::expect::assert_expected($year, $day);
+        }
+    }
+}
+
+/// A puzzle that has a solution implemented.
+///
+/// Every invocation of the `solutions!` macro submits one of these to the registry,
+/// so a new day only has to be declared as a module to be picked up by the runner
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parameters: &'static [Parameter],
+    pub solver: fn(String, &str, &Parameters, &Options) -> Result<Solution, ParseError>,
+    pub prepare: fn(&str, &Parameters) -> Result<ParsedInput, ParseError>,
+}
+
+impl Puzzle {
+    /// The file name shared by the input and expect file, either of the real input or of a named example
+    fn file_name(&self, example: Option<&str>) -> String {
+        match example {
+            Some(example) => format!("day{}.{example}.txt", self.day),
+            None => format!("day{}.txt", self.day),
+        }
+    }
+
+    /// The directory the inputs of the year are kept in
+    fn input_directory(&self) -> String {
+        format!("{}/{}", config::get().paths.inputs, self.year)
+    }
+
+    /// The file the puzzle input is read from
+    pub fn input_file(&self, example: Option<&str>) -> String {
+        format!("{}/{}", self.input_directory(), self.file_name(example))
+    }
+
+    /// The file the expected answers are read from
+    pub fn expect_file(&self, example: Option<&str>) -> String {
+        format!("{}/{}/{}", config::get().paths.expect, self.year, self.file_name(example))
+    }
+
+    /// The file parameter overrides for the input or a named example are read from
+    pub fn parameter_file(&self, example: Option<&str>) -> String {
+        format!("{}/{}", self.input_directory(), self.file_name(example).replace(".txt", ".params"))
+    }
+
+    /// Finds the names of the additional example inputs, which are stored next to the input as `day{day}.{name}.txt`
+    pub fn examples(&self) -> Vec<String> {
+        let prefix = format!("day{}.", self.day);
+
+        let Ok(entries) = fs::read_dir(self.input_directory()) else {
+            return vec![];
+        };
+
+        let mut examples: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter_map(|file| Some(file.strip_prefix(&prefix)?.strip_suffix(".txt")?.to_string()))
+            .filter(|example| !example.is_empty() && !example.contains('.'))
+            .collect();
+
+        examples.sort();
+        examples
+    }
+
+    /// The name of the puzzle as it is shown in the year table
+    pub fn name(&self) -> String {
+        format!("Day {}", self.day)
+    }
+
+    /// Reads the puzzle input or a named example
+    pub fn input(&self, example: Option<&str>) -> Result<String, Error> {
+        let file = self.input_file(example);
+        fs::read_to_string(&file).map_err(|_| Error::MissingInput(file))
+    }
+
+    /// Resolves the parameters for the input or a named example.
+    /// Overrides from the command line take precedence over the parameter file of the input,
+    /// which takes precedence over the configuration and then the defaults
+    pub fn parameters(&self, example: Option<&str>, options: &Options) -> Result<Parameters, Error> {
+        let mut parameters = Parameters::defaults(self.parameters);
+
+        if let Some(settings) = config::get().puzzle(self.year, self.day) {
+            for (name, value) in &settings.parameters {
+                parameters.set(name, *value).map_err(Error::InvalidParameter)?;
+            }
+        }
+
+        parameters.load(&self.parameter_file(example))?;
+
+        for (name, value) in &options.parameters {
+            if parameters.declares(name) {
+                parameters.set(name, *value).map_err(Error::InvalidParameter)?;
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    /// Reads the puzzle input or a named example and solves the puzzle with it
+    pub fn solve(&self, example: Option<&str>, options: &Options) -> Result<Solution, Error> {
+        let input = self.input(example)?;
+        let parameters = self.parameters(example, options)?;
+        let name = example.map_or_else(|| self.name(), str::to_string);
+
+        Ok((self.solver)(name, &input, &parameters, options)?)
+    }
+}
+
+inventory::collect!(Puzzle);
+
+/// Returns all registered puzzles, ordered by year and day
+pub fn puzzles() -> Vec<&'static Puzzle> {
+    let mut puzzles: Vec<&'static Puzzle> = inventory::iter::<Puzzle>.into_iter().collect();
+    puzzles.sort_by_key(|puzzle| (puzzle.year, puzzle.day));
+    puzzles
+}
+
+/// Returns all years that have at least one registered puzzle, in ascending order
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = puzzles().iter().map(|puzzle| puzzle.year).collect();
+    years.dedup();
+    years
+}
+
+/// Returns all registered puzzles of a year, ordered by day
+pub fn year(year: u16) -> Vec<&'static Puzzle> {
+    puzzles().into_iter().filter(|puzzle| puzzle.year == year).collect()
+}
+
+/// Looks up the puzzle of a specific year and day
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    inventory::iter::<Puzzle>.into_iter().find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
@@ -0,0 +1,292 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::{allocation::Allocations, config, error::Error, formatting::{format_solution, Redaction, Solution, TestResult, Timing}, registry::Puzzle};
+
+/// The outcome of running a single puzzle with its input or one of its named examples
+pub struct DayReport {
+    pub puzzle: &'static Puzzle,
+    pub example: Option<String>,
+    pub result: Result<Solution, Error>,
+}
+
+impl DayReport {
+    /// The name shown in the year table, which is the name of the example for examples
+    pub fn name(&self) -> String {
+        self.example.clone().unwrap_or_else(|| self.puzzle.name())
+    }
+
+    /// The file the expected answers of this input are read from
+    pub fn expect_file(&self) -> String {
+        self.puzzle.expect_file(self.example.as_deref())
+    }
+
+    /// Checks the answers against the expect file of this input
+    pub fn test(&self) -> Option<(TestResult, TestResult)> {
+        self.result.as_ref().ok().map(|solution| solution.test(&self.expect_file()))
+    }
+
+    /// The total time and the time budget of the puzzle in nanoseconds, if the real input took longer than its budget
+    pub fn over_budget(&self) -> Option<(u128, u128)> {
+        let budget = config::get().budget(self.puzzle.year, self.puzzle.day).filter(|_| self.example.is_none())?;
+        let time = self.result.as_ref().ok()?.total_time().median;
+
+        (time > budget).then_some((time, budget))
+    }
+}
+
+/// The outcomes of all selected puzzles of a year, in the order they were selected
+pub struct YearReport {
+    pub year: u16,
+    pub days: Vec<DayReport>,
+    /// How many days were solved at the same time. With more than one, the timings include the load of the other days
+    pub jobs: usize,
+}
+
+impl YearReport {
+    /// Checks whether the timings were taken while other days were solved in parallel
+    pub fn parallel(&self) -> bool {
+        self.jobs > 1
+    }
+
+    /// Checks whether any puzzle of the year could not be solved or gave a wrong answer
+    pub fn failed(&self) -> bool {
+        self.days.iter().any(|day| match day.test() {
+            Some((first, second)) => first == TestResult::Failure || second == TestResult::Failure,
+            None => true,
+        })
+    }
+}
+
+#[cfg(test)]
+impl YearReport {
+    /// A report of 2015 with the result of day 1, or of one of its examples, solved alone
+    pub fn single(example: Option<&str>, result: Result<Solution, Error>) -> YearReport {
+        let puzzle = crate::registry::find(2015, 1).expect("The puzzle is registered");
+        let day = DayReport { puzzle, example: example.map(str::to_string), result };
+
+        YearReport { year: 2015, days: vec![day], jobs: 1 }
+    }
+}
+
+/// The output formats of the runner
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A colored table for the terminal
+    #[default]
+    Table,
+    /// A JSON array with one record per part
+    Json,
+    /// CSV with one record per part
+    Csv,
+}
+
+/// A single part of a puzzle and input as it appears in the machine readable reports.
+/// Puzzles that could not be solved produce a single record without a part,
+/// parts that were not run are skipped and have neither answer nor time.
+/// Allocations are only present if they were counted
+#[derive(Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub example: Option<&'a str>,
+    pub part: Option<u8>,
+    pub answer: Option<Cow<'a, str>>,
+    pub status: Status,
+    pub input_time: Option<Timing>,
+    pub time: Option<Timing>,
+    pub error: Option<String>,
+    pub jobs: usize,
+    pub input_memory: Option<Allocations>,
+    pub memory: Option<Allocations>,
+}
+
+/// The status of a record, which extends the test result by failures to solve the puzzle at all
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Success,
+    Failure,
+    Unknown,
+    Skipped,
+    Error,
+}
+
+impl From<TestResult> for Status {
+    fn from(value: TestResult) -> Self {
+        match value {
+            TestResult::Success => Status::Success,
+            TestResult::Failure => Status::Failure,
+            TestResult::Unknown => Status::Unknown,
+            TestResult::NotRun => Status::Skipped,
+        }
+    }
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Success => "success",
+            Status::Failure => "failure",
+            Status::Unknown => "unknown",
+            Status::Skipped => "skipped",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// Flattens the reports into one record per year, day, input and part
+pub fn records(reports: &[YearReport], redaction: Redaction) -> Vec<Record<'_>> {
+    let mut records = vec![];
+
+    for report in reports {
+        for day in &report.days {
+            let solution = match &day.result {
+                Ok(solution) => solution,
+                Err(error) => {
+                    records.push(Record {
+                        year: report.year,
+                        day: day.puzzle.day,
+                        example: day.example.as_deref(),
+                        part: None,
+                        answer: None,
+                        status: Status::Error,
+                        input_time: None,
+                        time: None,
+                        error: Some(error.to_string()),
+                        jobs: report.jobs,
+                        input_memory: None,
+                        memory: None,
+                    });
+                    continue;
+                }
+            };
+
+            let (passed_1, passed_2) = solution.test(&day.expect_file());
+
+            let parts = [
+                (1, solution.solution_1.as_deref(), passed_1, solution.time_1, solution.memory_1),
+                (2, solution.solution_2.as_deref(), passed_2, solution.time_2, solution.memory_2),
+            ];
+
+            for (part, answer, passed, time, memory) in parts {
+                records.push(Record {
+                    year: report.year,
+                    day: day.puzzle.day,
+                    example: day.example.as_deref(),
+                    part: Some(part),
+                    answer: answer.map(|answer| format_solution(answer, redaction)),
+                    status: passed.into(),
+                    input_time: Some(solution.input_time),
+                    time: answer.map(|_| time),
+                    error: None,
+                    jobs: report.jobs,
+                    input_memory: solution.input_memory,
+                    memory,
+                });
+            }
+        }
+    }
+
+    records
+}
+
+/// Renders the reports as a JSON array of records
+pub fn json(reports: &[YearReport], redaction: Redaction) -> String {
+    serde_json::to_string_pretty(&records(reports, redaction)).expect("Records are always serializable")
+}
+
+/// Renders the reports as CSV, with all times in nanoseconds and all memory in bytes
+pub fn csv(reports: &[YearReport], redaction: Redaction) -> String {
+    let mut lines = vec!["year,day,example,part,answer,status,input_ns,time_ns,time_min_ns,time_mean_ns,time_stddev_ns,error,jobs,input_allocations,input_allocated_bytes,input_peak_bytes,allocations,allocated_bytes,peak_bytes".to_string()];
+
+    for record in records(reports, redaction) {
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            csv_field(record.example.unwrap_or_default()),
+            record.part.map(|part| part.to_string()).unwrap_or_default(),
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            record.status.as_str().to_string(),
+            record.input_time.map(|time| time.median.to_string()).unwrap_or_default(),
+            record.time.map(|time| time.median.to_string()).unwrap_or_default(),
+            record.time.map(|time| time.min.to_string()).unwrap_or_default(),
+            record.time.map(|time| time.mean.to_string()).unwrap_or_default(),
+            record.time.map(|time| time.stddev.to_string()).unwrap_or_default(),
+            csv_field(&record.error.unwrap_or_default()),
+            record.jobs.to_string(),
+            record.input_memory.map(|memory| memory.count.to_string()).unwrap_or_default(),
+            record.input_memory.map(|memory| memory.bytes.to_string()).unwrap_or_default(),
+            record.input_memory.map(|memory| memory.peak.to_string()).unwrap_or_default(),
+            record.memory.map(|memory| memory.count.to_string()).unwrap_or_default(),
+            record.memory.map(|memory| memory.bytes.to_string()).unwrap_or_default(),
+            record.memory.map(|memory| memory.peak.to_string()).unwrap_or_default(),
+        ];
+
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n")
+}
+
+/// Quotes a field if it contains characters that have a meaning in CSV
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(result: Result<Solution, Error>) -> Vec<YearReport> {
+        vec![YearReport::single(Some("quoting"), result)]
+    }
+
+    fn solution(part_1: &str, part_2: &str) -> Solution {
+        Solution {
+            input_time: Timing::single(1),
+            time_1: Timing::single(2),
+            time_2: Timing::single(3),
+            ..Solution::answered(Some(part_1), Some(part_2))
+        }
+    }
+
+    #[test]
+    fn quotes_only_fields_that_need_it() {
+        assert_eq!("plain", csv_field("plain"));
+        assert_eq!("\"1,2\"", csv_field("1,2"));
+        assert_eq!("\"say \"\"hi\"\"\"", csv_field("say \"hi\""));
+        assert_eq!("\"two\nlines\"", csv_field("two\nlines"));
+    }
+
+    #[test]
+    fn quotes_answers() {
+        let csv = csv(&report(Ok(solution("1,\"2\"", "plain"))), Redaction::Shown);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(3, lines.len());
+        assert!(lines[1].starts_with("2015,1,quoting,1,\"1,\"\"2\"\"\",unknown,1,2,"));
+        assert!(lines[2].starts_with("2015,1,quoting,2,plain,unknown,1,3,"));
+        assert_eq!(lines[0].split(',').count(), lines[2].split(',').count());
+    }
+
+    #[test]
+    fn quotes_errors() {
+        let csv = csv(&report(Err(Error::Panicked("left, right".to_string()))), Redaction::Shown);
+
+        assert!(csv.lines().nth(1).unwrap().contains(",\"panicked: left, right\",1,"));
+    }
+
+    #[test]
+    fn redacts_answers() {
+        let csv = csv(&report(Ok(solution("1,\"2\"", "plain"))), Redaction::Hidden);
+
+        assert!(!csv.contains("plain"));
+        assert!(!csv.contains("\"\"2\"\""));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{fs, path::Path};
+
+use crate::config;
+
+/// The module of a new day. The parts panic until they are implemented, which shows up as a panicked row in the table
+const TEMPLATE: &str = r#"use crate::{error::ParseError, solutions, solver::Solver};
+
+solutions!{{year}, {day}, "{title}", Day{day}}
+
+pub struct Day{day};
+
+impl Solver for Day{day} {
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Answer1 {
+        todo!()
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {
+        todo!()
+    }
+}
+"#;
+
+/// Creates the module of a new day from the template and declares it in its year module, creating the year if needed.
+/// An empty expect file and input are created as placeholders, unless they already exist.
+///
+/// Paths are relative to the repository root, like the inputs. Returns the files that were created or changed
+pub fn new_day(year: u16, day: u8, title: &str) -> Result<Vec<String>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {day} is not a day of advent"));
+    }
+
+    if !Path::new("src/lib.rs").exists() {
+        return Err("New days can only be created from the root of the repository".to_string());
+    }
+
+    let module = format!("src/y{year}/day{day}.rs");
+
+    if Path::new(&module).exists() {
+        return Err(format!("{module} already exists"));
+    }
+
+    let mut changed = vec![];
+
+    let year_module = format!("src/y{year}/mod.rs");
+
+    if !Path::new(&year_module).exists() {
+        declare_module("src/lib.rs", &format!("y{year}"), year_number)?;
+        changed.push("src/lib.rs".to_string());
+    }
+
+    let source = TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{title}", &title.replace('\\', "\\\\").replace('"', "\\\""));
+
+    write(&module, &source)?;
+    changed.push(module);
+
+    declare_module(&year_module, &format!("day{day}"), day_number)?;
+    changed.push(year_module);
+
+    let paths = &config::get().paths;
+
+    for placeholder in [format!("{}/{year}/day{day}.txt", paths.expect), format!("{}/{year}/day{day}.txt", paths.inputs)] {
+        if !Path::new(&placeholder).exists() {
+            write(&placeholder, "")?;
+            changed.push(placeholder);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Writes the file, creating its directory if needed
+fn write(file: &str, content: &str) -> Result<(), String> {
+    Path::new(file).parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(file, content))
+        .map_err(|error| format!("Could not write {file}: {error}"))
+}
+
+/// The number of a year module like `y2024`
+fn year_number(module: &str) -> Option<u32> {
+    module.strip_prefix('y')?.parse().ok()
+}
+
+/// The number of a day module like `day7`
+fn day_number(module: &str) -> Option<u32> {
+    module.strip_prefix("day")?.parse().ok()
+}
+
+/// Adds `pub mod {name};` to the file, keeping the numbered modules of the same kind in order
+fn declare_module(file: &str, name: &str, number: fn(&str) -> Option<u32>) -> Result<(), String> {
+    let content = fs::read_to_string(file).unwrap_or_default();
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let position = |line: &str| line.strip_prefix("pub mod ")?.strip_suffix(';').and_then(number);
+    let new_number = number(name).expect("The module name is numbered");
+
+    let declarations: Vec<(usize, u32)> = lines.iter().enumerate()
+        .filter_map(|(index, line)| Some((index, position(line)?)))
+        .collect();
+
+    let index = declarations.iter()
+        .find(|(_, existing)| *existing > new_number)
+        .map(|(index, _)| *index)
+        .or_else(|| declarations.last().map(|(index, _)| index + 1))
+        .unwrap_or(0);
+
+    lines.insert(index, format!("pub mod {name};"));
+
+    write(file, &(lines.join("\n") + "\n"))
+}
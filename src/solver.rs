@@ -0,0 +1,110 @@
+use std::{fmt::Display, hint::black_box, time::Duration};
+
+use crate::{error::ParseError, formatting::{measure, Solution}, parameters::Parameters};
+
+/// A solver for a single puzzle, split into parsing the input and solving each of its parts.
+///
+/// Solvers work on the puzzle input as text and never touch the filesystem, so they can be driven
+/// from the runner as well as from tests, benchmarks or other binaries
+///
+/// ```
+/// use aoc::{solver::Solver, y2024::day1::Day1};
+///
+/// let input = Day1::parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3").unwrap();
+///
+/// assert_eq!(11, Day1::part1(&input));
+/// assert_eq!(31, Day1::part2(&input));
+/// ```
+pub trait Solver {
+    /// The parsed puzzle input, which is shared by both parts
+    type Input;
+
+    /// The answer to the first part
+    type Answer1: Display;
+
+    /// The answer to the second part
+    type Answer2: Display;
+
+    /// Parses the puzzle input, failing if it does not have the expected format
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the puzzle input with the parameters declared in `solutions!`.
+    /// Solvers with parameters implement this and keep the values they need in their input,
+    /// all other solvers only implement `parse`
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, ParseError> {
+        let _ = parameters;
+        Self::parse(input)
+    }
+
+    /// Solves the first part of the puzzle
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    /// Solves the second part of the puzzle
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Controls how a solver is evaluated
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// Benchmark each step over this many runs instead of timing it once
+    pub bench: Option<u32>,
+    /// Only run this part, skipping the other one
+    pub part: Option<u8>,
+    /// Give up on a puzzle that takes longer than this, when it is run in isolation
+    pub timeout: Option<Duration>,
+    /// Override these parameters for all puzzles that declare them
+    pub parameters: Vec<(String, i64)>,
+    /// Count the allocations of each step, which requires the counting allocator to be installed
+    pub memory: bool,
+}
+
+impl Options {
+    /// Checks whether the part is selected to be run
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+/// Parses the input and solves the selected parts using the solver, timing each step
+pub fn evaluate<S: Solver>(name: String, input: &str, parameters: &Parameters, options: &Options) -> Result<Solution, ParseError> {
+    let (input, input_time, input_memory) = measure(|| S::parse_with(input, parameters), options.bench, options.memory);
+    let input = input?;
+
+    Ok(Solution::evaluated(
+        name,
+        options.runs_part(1).then_some(|| S::part1(&input)),
+        options.runs_part(2).then_some(|| S::part2(&input)),
+        (input_time, input_memory),
+        options
+    ))
+}
+
+/// A parsed puzzle input whose solver is only known to the registry.
+/// This lets benchmarks time the parts separately for every registered puzzle
+pub trait Parsed {
+    /// Solves the first part, discarding the answer
+    fn part1(&self);
+
+    /// Solves the second part, discarding the answer
+    fn part2(&self);
+}
+
+/// A parsed input of any solver
+pub type ParsedInput = Box<dyn Parsed>;
+
+struct Input<S: Solver>(S::Input);
+
+impl<S: Solver> Parsed for Input<S> {
+    fn part1(&self) {
+        black_box(S::part1(&self.0));
+    }
+
+    fn part2(&self) {
+        black_box(S::part2(&self.0));
+    }
+}
+
+/// Parses the input using the solver, keeping it for the parts to be run later
+pub fn prepare<S: Solver + 'static>(input: &str, parameters: &Parameters) -> Result<ParsedInput, ParseError> {
+    Ok(Box::new(Input::<S>(S::parse_with(input, parameters)?)))
+}
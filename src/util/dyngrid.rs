@@ -0,0 +1,98 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::error::ParseError;
+
+use super::grid::Grid;
+
+/// The dyngrid stores a two dimensional grid of a size only known at **runtime** in
+/// a one dimensional vector, like the flatgrid does for sizes known at compile time.
+///
+/// It is meant for grids whose size depends on the input, where a flatgrid would have to hard code the size of one input.
+/// The index arithmetic is provided by the `Grid` trait, which needs to be in scope
+///
+/// ```
+/// # use aoc::util::{dyngrid::DynGrid, grid::Grid};
+/// let mut grid: DynGrid<u32> = DynGrid::filled(3, 2, 0);
+/// let index = grid.to_index(2, 1);
+/// grid[index] = 7;
+///
+/// assert_eq!(6, grid.len());
+/// assert_eq!((2, 1), grid.to_coordinates(5));
+/// assert_eq!(7, grid[grid.moved(0, 2, 1)]);
+/// assert!(grid.will_vertical_move_cross_border(index, 1));
+/// ```
+///
+/// Like the flatgrid, it implements Deref and DerefMut to the underlying vector
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynGrid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl <T> DynGrid<T> {
+    /// Creates a new, empty DynGrid. The inner vector preallocates the neccessary capacity for width * height
+    pub fn new(width: usize, height: usize) -> DynGrid<T> {
+        DynGrid {
+            data: Vec::with_capacity(width * height),
+            width,
+            height,
+        }
+    }
+
+    /// Creates a new DynGrid filled with the specified value
+    pub fn filled(width: usize, height: usize, value: T) -> DynGrid<T> where T: Clone {
+        DynGrid {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a DynGrid from the cells, which are stored row by row
+    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> DynGrid<T> {
+        debug_assert_eq!(width * height, data.len(), "The cells fill the grid");
+
+        DynGrid { data, width, height }
+    }
+
+    /// Takes the cells out of the grid
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl <T> Grid<T> for DynGrid<T> {
+    #[inline(always)]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline(always)]
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Accepts cells of any size, as long as they fill the grid
+    fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self, ParseError> {
+        if width * height != cells.len() {
+            return Err(ParseError::Malformed("The cells do not fill the grid"));
+        }
+
+        Ok(DynGrid { data: cells, width, height })
+    }
+}
+
+impl <T> Deref for DynGrid<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl <T> DerefMut for DynGrid<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.data
+    }
+}
@@ -1,11 +1,16 @@
 use std::ops::{Deref, DerefMut, Range};
 
+use crate::error::ParseError;
+
+use super::grid::{self, Grid};
+
 /// The flatgrid stores a two dimensional grid of a size known at **compile time** in 
 /// a one dimensional vector to avoid the performance cost of indexing nested vectors.
 /// 
 /// A flatgrid can be created and pushed to
 /// ```
-/// let grid: FlatGrid<u32, 64, 64> = FlatGrid::new();
+/// # use aoc::util::flatgrid::FlatGrid;
+/// let mut grid: FlatGrid<u32, 64, 64> = FlatGrid::new();
 /// grid.push(1);
 /// grid.push(2);
 /// ```
@@ -14,14 +19,16 @@ use std::ops::{Deref, DerefMut, Range};
 /// vector function is avalaible. Therefore a flatgrid can also be indixed.
 /// 
 /// ```
-/// # let grid: FlatGrid<u32, 64, 64> = FlatGrid::new();
+/// # use aoc::util::flatgrid::FlatGrid;
+/// # let mut grid: FlatGrid<u32, 64, 64> = FlatGrid::new();
 /// # grid.push(1);
 /// # grid.push(2);
 /// assert_eq!(1, grid[0]);
 /// ```
 /// 
 /// A flatgrid is indexed by a onedimensional index of type usize, just like a normal vec,
-/// but provides many methods to manipulate indizes to move them in 2D space
+/// but provides many methods to manipulate indizes to move them in 2D space.
+/// The same methods are available through the `Grid` trait, which the dyngrid implements as well
 #[derive(Clone)]
 pub struct FlatGrid<T, const W: usize, const H: usize> {
     data: Vec<T>
@@ -162,6 +169,62 @@ impl <T, const W: usize, const H: usize> FlatGrid<T, W, H> {
         (index as isize + Self::movement(horizontal, vertical)) as usize
     }
 
+    /// Moves an index both horizontally and vertically, if it stays on the grid.
+    /// Unlike `moved`, leaving the grid through the left or right edge does not end up in another row
+    ///
+    /// ```
+    /// # use aoc::util::flatgrid::FlatGrid;
+    /// type Grid = FlatGrid<u8, 3, 3>;
+    ///
+    /// assert_eq!(3, Grid::moved(2, 1, 0));
+    /// assert_eq!(None, Grid::try_moved(2, 1, 0));
+    /// assert_eq!(Some(5), Grid::try_moved(2, 0, 1));
+    /// ```
+    #[inline(always)]
+    pub fn try_moved(index: usize, horizontal: isize, vertical: isize) -> Option<usize> {
+        grid::try_moved(W, H, index, horizontal, vertical)
+    }
+
+    /// Moves an index both horizontally and vertically, wrapping around at the edges of the grid like a torus
+    ///
+    /// ```
+    /// # use aoc::util::flatgrid::FlatGrid;
+    /// type Grid = FlatGrid<u8, 3, 3>;
+    ///
+    /// assert_eq!(0, Grid::wrapped(2, 1, 0));
+    /// assert_eq!(6, Grid::wrapped(0, 0, -1));
+    /// ```
+    #[inline(always)]
+    pub fn wrapped(index: usize, horizontal: isize, vertical: isize) -> usize {
+        grid::wrapped(W, H, index, horizontal, vertical)
+    }
+
+    /// Iterates over the direct neighbours of the index that are on the grid, together with the movement to them
+    ///
+    /// ```
+    /// # use aoc::util::flatgrid::FlatGrid;
+    /// type Grid = FlatGrid<u8, 3, 3>;
+    ///
+    /// assert_eq!(vec![(1, (1, 0)), (3, (0, 1))], Grid::neighbors4(0).collect::<Vec<_>>());
+    /// assert_eq!(8, Grid::neighbors8(4).count());
+    /// ```
+    #[inline(always)]
+    pub fn neighbors4(index: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+        grid::neighbors4(W, H, index)
+    }
+
+    /// Iterates over the direct and diagonal neighbours of the index that are on the grid, together with the movement to them
+    #[inline(always)]
+    pub fn neighbors8(index: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+        grid::neighbors8(W, H, index)
+    }
+
+    /// Iterates over all indices on the grid within the Manhattan distance of the index, together with the movement to them
+    #[inline(always)]
+    pub fn neighbors_within(index: usize, radius: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+        grid::neighbors_within(W, H, index, radius)
+    }
+
     /// Provides an iterator over the entire grids indices
     #[inline(always)]
     pub const fn indices() -> Range<usize> {
@@ -176,6 +239,29 @@ impl <T, const W: usize, const H: usize> FlatGrid<T, W, H> {
 
 }
 
+impl <T, const W: usize, const H: usize> Grid<T> for FlatGrid<T, W, H> {
+    #[inline(always)]
+    fn width(&self) -> usize {
+        W
+    }
+
+    #[inline(always)]
+    fn height(&self) -> usize {
+        H
+    }
+
+    /// Only accepts cells of exactly the size of the flatgrid
+    fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self, ParseError> {
+        if width > W || height > H {
+            Err(ParseError::Malformed("The grid is larger than expected"))
+        } else if width < W || height < H {
+            Err(ParseError::Malformed("The grid is smaller than expected"))
+        } else {
+            Ok(cells.into())
+        }
+    }
+}
+
 impl <T, const W: usize, const H: usize> From<Vec<T>> for FlatGrid<T, W, H> {
     fn from(value: Vec<T>) -> Self {
         Self {
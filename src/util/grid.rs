@@ -0,0 +1,337 @@
+use std::ops::{DerefMut, Range};
+
+use crate::error::ParseError;
+
+/// The movements to the four direct neighbours, as horizontal and vertical movement
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The movements to all eight neighbours, including the diagonal ones
+pub const DIRECTIONS_8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Moves the index on a grid of the given size, if it stays on the grid.
+/// Unlike moving the index directly, leaving the grid through the left or right edge does not end up in another row
+///
+/// ```
+/// # use aoc::util::grid::try_moved;
+/// assert_eq!(Some(4), try_moved(3, 3, 0, 1, 1));
+/// assert_eq!(None, try_moved(3, 3, 2, 1, 0));
+/// assert_eq!(None, try_moved(3, 3, 1, 0, -1));
+/// ```
+#[inline(always)]
+pub fn try_moved(width: usize, height: usize, index: usize, horizontal: isize, vertical: isize) -> Option<usize> {
+    let x = (index % width).checked_add_signed(horizontal).filter(|&x| x < width)?;
+    let y = (index / width).checked_add_signed(vertical).filter(|&y| y < height)?;
+
+    Some(x + y * width)
+}
+
+/// Moves the index on a grid of the given size, which wraps around at its edges like a torus
+///
+/// ```
+/// # use aoc::util::grid::wrapped;
+/// assert_eq!(0, wrapped(3, 3, 2, 1, 0));
+/// assert_eq!(7, wrapped(3, 3, 1, 0, -1));
+/// assert_eq!(8, wrapped(3, 3, 0, -4, 5));
+/// ```
+#[inline(always)]
+pub fn wrapped(width: usize, height: usize, index: usize, horizontal: isize, vertical: isize) -> usize {
+    let x = ((index % width) as isize + horizontal).rem_euclid(width as isize) as usize;
+    let y = ((index / width) as isize + vertical).rem_euclid(height as isize) as usize;
+
+    x + y * width
+}
+
+/// The direct neighbours of the index on a grid of the given size, together with the movement to them.
+/// Neighbours that would be off the grid are left out
+#[inline(always)]
+pub fn neighbors4(width: usize, height: usize, index: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+    DIRECTIONS_4.into_iter()
+        .filter_map(move |(horizontal, vertical)| Some((try_moved(width, height, index, horizontal, vertical)?, (horizontal, vertical))))
+}
+
+/// The direct and diagonal neighbours of the index on a grid of the given size, together with the movement to them.
+/// Neighbours that would be off the grid are left out
+#[inline(always)]
+pub fn neighbors8(width: usize, height: usize, index: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+    DIRECTIONS_8.into_iter()
+        .filter_map(move |(horizontal, vertical)| Some((try_moved(width, height, index, horizontal, vertical)?, (horizontal, vertical))))
+}
+
+/// All indices within the Manhattan distance of the index on a grid of the given size, together with the movement to them.
+/// The index itself is left out, and the rows and columns are clamped to the grid, so no index has to be checked
+///
+/// ```
+/// # use aoc::util::grid::neighbors_within;
+/// // The full diamond of radius 2 has 12 indices besides its center
+/// assert_eq!(12, neighbors_within(5, 5, 12, 2).count());
+/// // In the corner, only a quarter of it is on the grid
+/// assert_eq!(5, neighbors_within(5, 5, 0, 2).count());
+/// assert!(neighbors_within(5, 5, 0, 2).all(|(index, (horizontal, vertical))| index as isize == horizontal + vertical * 5));
+/// ```
+pub fn neighbors_within(width: usize, height: usize, index: usize, radius: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+    let (x, y) = ((index % width) as isize, (index / width) as isize);
+    let (width, height, radius) = (width as isize, height as isize, radius as isize);
+
+    ((-radius).max(-y)..=radius.min(height - 1 - y)).flat_map(move |vertical| {
+        let remaining = radius - vertical.abs();
+
+        ((-remaining).max(-x)..=remaining.min(width - 1 - x))
+            .filter(move |&horizontal| horizontal != 0 || vertical != 0)
+            .map(move |horizontal| ((x + horizontal + (y + vertical) * width) as usize, (horizontal, vertical)))
+    })
+}
+
+/// The index arithmetic shared by all grids that store a two dimensional grid row by row in a single vector.
+///
+/// Implemented by the flatgrid, whose size is known at compile time, and the dyngrid, whose size is only known
+/// at runtime, so a solver can be written once for both. Only the size has to be provided,
+/// everything else is derived from it
+///
+/// ```
+/// # use aoc::util::{dyngrid::DynGrid, flatgrid::FlatGrid, grid::Grid};
+/// fn right_neighbour<T, G: Grid<T>>(grid: &G, index: usize) -> Option<&T> {
+///     (!grid.will_horizontal_move_cross_border(index, 1)).then(|| &grid[grid.moved_horizontally(index, 1)])
+/// }
+///
+/// let flat: FlatGrid<u8, 2, 2> = FlatGrid::from(vec![1, 2, 3, 4]);
+/// let dynamic = DynGrid::from_vec(2, 2, vec![1, 2, 3, 4]);
+///
+/// assert_eq!(Some(&2), right_neighbour(&flat, 0));
+/// assert_eq!(None, right_neighbour(&dynamic, 1));
+/// ```
+///
+/// Grids can also be parsed from text with one row per line, mapping every character to a cell.
+/// The positions of marker characters, like the start of a maze, are returned with the grid
+///
+/// ```
+/// # use aoc::util::{dyngrid::DynGrid, flatgrid::FlatGrid, grid::Grid};
+/// let text = "#.S\n.E#\n";
+///
+/// let (maze, [start, end]): (FlatGrid<bool, 3, 2>, _) = FlatGrid::parse_with_markers(text, ['S', 'E'], |c| c == '#').unwrap();
+/// assert_eq!((2, 0), maze.to_coordinates(start));
+/// assert_eq!((1, 1), maze.to_coordinates(end));
+///
+/// // The size of a dyngrid is taken from the text
+/// let digits: DynGrid<u32> = DynGrid::try_parse("123\n456", |c| c.to_digit(10).ok_or(aoc::error::ParseError::Malformed("Not a digit"))).unwrap();
+/// assert_eq!((3, 2), (digits.width(), digits.height()));
+///
+/// // Ragged rows and grids that do not fit are errors
+/// assert!(DynGrid::parse("12\n3", |c| c).is_err());
+/// assert!(FlatGrid::<char, 3, 2>::parse("1234\n5678", |c| c).is_err());
+/// ```
+pub trait Grid<T>: DerefMut<Target = Vec<T>> {
+    /// Returns the grids width
+    fn width(&self) -> usize;
+
+    /// Returns the grids height
+    fn height(&self) -> usize;
+
+    /// Creates a grid of the given size from its cells, stored row by row. Fails if the grid cannot have this size
+    fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self, ParseError> where Self: Sized;
+
+    /// Parses a grid from text with one row per line, mapping every character to a cell
+    fn parse<F>(text: &str, mut map: F) -> Result<Self, ParseError> where Self: Sized, F: FnMut(char) -> T {
+        Self::try_parse(text, |character| Ok(map(character)))
+    }
+
+    /// Parses a grid from text with one row per line, mapping every character to a cell or failing on unexpected characters
+    fn try_parse<F>(text: &str, map: F) -> Result<Self, ParseError> where Self: Sized, F: FnMut(char) -> Result<T, ParseError> {
+        Self::try_parse_with_markers(text, [], map).map(|(grid, [])| grid)
+    }
+
+    /// Parses a grid like `parse`, additionally returning the index of each marker character
+    fn parse_with_markers<F, const N: usize>(text: &str, markers: [char; N], mut map: F) -> Result<(Self, [usize; N]), ParseError> where Self: Sized, F: FnMut(char) -> T {
+        Self::try_parse_with_markers(text, markers, |character| Ok(map(character)))
+    }
+
+    /// Parses a grid like `try_parse`, additionally returning the index of each marker character.
+    /// Markers are mapped like any other character, and every marker has to appear exactly once
+    fn try_parse_with_markers<F, const N: usize>(text: &str, markers: [char; N], mut map: F) -> Result<(Self, [usize; N]), ParseError> where Self: Sized, F: FnMut(char) -> Result<T, ParseError> {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut positions = [None; N];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in text.trim_end_matches(['\r', '\n']).lines() {
+            let row_start = cells.len();
+
+            for character in line.chars() {
+                if let Some(marker) = markers.iter().position(|&marker| marker == character) {
+                    if positions[marker].replace(cells.len()).is_some() {
+                        return Err(ParseError::Malformed("A marker appears more than once in the grid"));
+                    }
+                }
+
+                cells.push(map(character)?);
+            }
+
+            let row_length = cells.len() - row_start;
+
+            if *width.get_or_insert(row_length) != row_length {
+                return Err(ParseError::Malformed("The rows of the grid have different lengths"));
+            }
+
+            height += 1;
+        }
+
+        let width = width.filter(|&width| width > 0).ok_or(ParseError::Malformed("The grid is empty"))?;
+
+        let mut found = [0; N];
+
+        for (found, position) in found.iter_mut().zip(positions) {
+            *found = position.ok_or(ParseError::Malformed("A marker is missing from the grid"))?;
+        }
+
+        Ok((Self::from_cells(width, height, cells)?, found))
+    }
+
+    /// Returns the grids area, which should be equal to its length
+    #[inline(always)]
+    fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// Gets the x coordiante of an index
+    #[inline(always)]
+    fn x_coordinate(&self, index: usize) -> usize {
+        index % self.width()
+    }
+
+    /// Gets the y coordinate of an index
+    #[inline(always)]
+    fn y_coordinate(&self, index: usize) -> usize {
+        index / self.width()
+    }
+
+    /// Splits the index into its x and y coordinates
+    #[inline(always)]
+    fn to_coordinates(&self, index: usize) -> (usize, usize) {
+        (self.x_coordinate(index), self.y_coordinate(index))
+    }
+
+    /// Transforms x and y coordinates into an index
+    #[inline(always)]
+    fn to_index(&self, x: usize, y: usize) -> usize {
+        x + y * self.width()
+    }
+
+    /// Calculates the distance to the left edge from the index
+    #[inline(always)]
+    fn distance_to_left_edge(&self, index: usize) -> usize {
+        self.x_coordinate(index)
+    }
+
+    /// Calculates the distance to the bottom edge from the index
+    #[inline(always)]
+    fn distance_to_bottom_edge(&self, index: usize) -> usize {
+        self.y_coordinate(index)
+    }
+
+    /// Calculates the distance to the right edge from the index
+    #[inline(always)]
+    fn distance_to_right_edge(&self, index: usize) -> usize {
+        self.width() - self.x_coordinate(index) - 1
+    }
+
+    /// Calculates the distance to the top edge from the index
+    #[inline(always)]
+    fn distance_to_top_edge(&self, index: usize) -> usize {
+        self.height() - self.y_coordinate(index) - 1
+    }
+
+    /// Checks if a horizontal move starting from the index will cross a border
+    fn will_horizontal_move_cross_border(&self, index: usize, direction: isize) -> bool {
+        if direction < 0 {
+            direction.unsigned_abs() > self.distance_to_left_edge(index)
+        } else {
+            direction as usize > self.distance_to_right_edge(index)
+        }
+    }
+
+    /// Checks if a vertical move starting from the index will cross a border
+    fn will_vertical_move_cross_border(&self, index: usize, direction: isize) -> bool {
+        if direction < 0 {
+            direction.unsigned_abs() > self.distance_to_bottom_edge(index)
+        } else {
+            direction as usize > self.distance_to_top_edge(index)
+        }
+    }
+
+    /// Gets the index movement for a horizontal movement
+    #[inline(always)]
+    fn horizontal_movement(&self, amount: isize) -> isize {
+        amount
+    }
+
+    /// Gets the index movement for a vertical movement
+    #[inline(always)]
+    fn vertical_movement(&self, amount: isize) -> isize {
+        amount * self.width() as isize
+    }
+
+    /// Gets the index movement for a combined horizontal and vertical movement
+    #[inline(always)]
+    fn movement(&self, horizontal: isize, vertical: isize) -> isize {
+        self.vertical_movement(vertical) + self.horizontal_movement(horizontal)
+    }
+
+    /// Moves an index horizontally
+    #[inline(always)]
+    fn moved_horizontally(&self, index: usize, amount: isize) -> usize {
+        (index as isize + self.horizontal_movement(amount)) as usize
+    }
+
+    /// Moves an index vertically
+    #[inline(always)]
+    fn moved_vertically(&self, index: usize, amount: isize) -> usize {
+        (index as isize + self.vertical_movement(amount)) as usize
+    }
+
+    /// Moves an index both horizontally and vertically
+    #[inline(always)]
+    fn moved(&self, index: usize, horizontal: isize, vertical: isize) -> usize {
+        (index as isize + self.movement(horizontal, vertical)) as usize
+    }
+
+    /// Moves an index both horizontally and vertically, if it stays on the grid
+    #[inline(always)]
+    fn try_moved(&self, index: usize, horizontal: isize, vertical: isize) -> Option<usize> {
+        try_moved(self.width(), self.height(), index, horizontal, vertical)
+    }
+
+    /// Moves an index both horizontally and vertically, wrapping around at the edges of the grid
+    #[inline(always)]
+    fn wrapped(&self, index: usize, horizontal: isize, vertical: isize) -> usize {
+        wrapped(self.width(), self.height(), index, horizontal, vertical)
+    }
+
+    /// Iterates over the direct neighbours of the index that are on the grid, together with the movement to them
+    #[inline(always)]
+    fn neighbors4(&self, index: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+        neighbors4(self.width(), self.height(), index)
+    }
+
+    /// Iterates over the direct and diagonal neighbours of the index that are on the grid, together with the movement to them
+    #[inline(always)]
+    fn neighbors8(&self, index: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+        neighbors8(self.width(), self.height(), index)
+    }
+
+    /// Iterates over all indices on the grid within the Manhattan distance of the index, together with the movement to them
+    #[inline(always)]
+    fn neighbors_within(&self, index: usize, radius: usize) -> impl Iterator<Item = (usize, (isize, isize))> {
+        neighbors_within(self.width(), self.height(), index, radius)
+    }
+
+    /// Provides an iterator over the entire grids indices
+    #[inline(always)]
+    fn indices(&self) -> Range<usize> {
+        0..self.area()
+    }
+
+    /// Returns the last index on the grid
+    #[inline(always)]
+    fn last_index(&self) -> usize {
+        self.area() - 1
+    }
+}
@@ -1 +1,3 @@
-pub mod flatgrid;
\ No newline at end of file
+pub mod dyngrid;
+pub mod flatgrid;
+pub mod grid;
\ No newline at end of file
@@ -0,0 +1,211 @@
+use std::{env, fs, io::{self, Write}, path::{Path, PathBuf}, process::{Command, ExitCode}, thread, time::{Duration, SystemTime}};
+
+use crate::{allocation, args::{self, Args}, baseline::Baseline, formatting::{year, Redaction, TableOptions}, registry::Puzzle, report::YearReport};
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The sources that trigger a rebuild when they change
+const SOURCE_DIRECTORY: &str = "src";
+
+/// Clears the terminal and moves the cursor to the top left corner
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// The modification times of a set of files, so a file that changed, appeared or disappeared is noticed
+type Snapshot = Vec<(PathBuf, SystemTime)>;
+
+/// Solves the selected days and renders their tables, again every time one of their inputs, parameters or expect files changes.
+/// Answers that changed since the last run are highlighted, and the timings are compared to the last run unless a baseline is given.
+/// With `--rebuild` the runner is rebuilt and restarted when a source file changes
+pub fn run(args: &Args, baseline: Option<&Baseline>, redaction: Redaction) -> ExitCode {
+    // After a rebuild the running executable has been replaced, so its path is taken up front
+    let executable = match env::current_exe() {
+        Ok(executable) => executable,
+        Err(error) => {
+            eprintln!("Could not locate the runner: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut previous: Option<Vec<YearReport>> = None;
+    let mut sources = args.rebuild.then(|| snapshot(&source_files()));
+
+    loop {
+        let files = match watched_files(args) {
+            Ok(files) => files,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::from(2);
+            }
+        };
+        let inputs = snapshot(&files);
+
+        let years = match args.years() {
+            Ok(years) => years,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::from(2);
+            }
+        };
+
+        render(args, baseline, redaction, &years, previous.as_deref());
+        previous = Some(years);
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            if let Some(sources) = &mut sources {
+                let current = snapshot(&source_files());
+
+                if current != *sources {
+                    *sources = current;
+
+                    if rebuild() {
+                        return restart(&executable);
+                    }
+
+                    eprintln!("The build failed, watching for further changes");
+                }
+            }
+
+            // Examples may have been added or removed, so the files are looked up again
+            if watched_files(args).is_ok_and(|files| snapshot(&files) != inputs) {
+                break;
+            }
+        }
+    }
+}
+
+/// Clears the terminal and prints the tables of all years
+fn render(args: &Args, baseline: Option<&Baseline>, redaction: Redaction, years: &[YearReport], previous: Option<&[YearReport]>) {
+    let last_run = previous.map(|previous| {
+        let mut last_run = Baseline::default();
+        last_run.update(previous);
+        last_run
+    });
+
+    let table_options = TableOptions {
+        redaction,
+        bench: args.bench.is_some(),
+        memory: args.memory,
+        baseline: baseline.or(last_run.as_ref()),
+        regression_threshold: args.regression_threshold,
+        previous,
+    };
+
+    let output = years.iter()
+        .map(|report| year(report, &table_options))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let mut stdout = io::stdout().lock();
+    write!(stdout, "{CLEAR_SCREEN}{output}\n\nWatching for changes, press Ctrl+C to stop\n").unwrap_or(());
+    stdout.flush().unwrap_or(());
+}
+
+/// All inputs, parameter files and expect files of the selected puzzles, including their examples
+fn watched_files(args: &Args) -> Result<Vec<PathBuf>, String> {
+    let selection = args::select(&args.year, &args.day, &args.selection).map_err(|error| error.to_string())?;
+
+    let mut files = vec![];
+
+    for puzzle in selection.into_iter().flat_map(|(_, puzzles)| puzzles) {
+        for file in [puzzle.input_file(None), puzzle.expect_file(None)] {
+            let Some(directory) = Path::new(&file).parent() else {
+                continue;
+            };
+
+            let Ok(entries) = fs::read_dir(directory) else {
+                continue;
+            };
+
+            files.extend(entries.flatten()
+                .map(|entry| entry.path())
+                .filter(|path| belongs_to(puzzle, path)));
+        }
+    }
+
+    Ok(files)
+}
+
+/// Checks whether the file is the input, an example or a parameter file of the puzzle
+fn belongs_to(puzzle: &Puzzle, path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+
+    name.starts_with(&format!("day{}.", puzzle.day))
+}
+
+/// All files under the source directory
+fn source_files() -> Vec<PathBuf> {
+    let mut files = vec![];
+    let mut directories = vec![PathBuf::from(SOURCE_DIRECTORY)];
+
+    while let Some(directory) = directories.pop() {
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                directories.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    files
+}
+
+/// Takes the modification times of the files, ordered by path so two snapshots can be compared directly
+fn snapshot(files: &[PathBuf]) -> Snapshot {
+    let mut snapshot: Snapshot = files.iter()
+        .filter_map(|file| Some((file.clone(), fs::metadata(file).and_then(|metadata| metadata.modified()).ok()?)))
+        .collect();
+
+    snapshot.sort();
+    snapshot
+}
+
+/// Rebuilds the runner with the same profile it was built with, returning whether the build succeeded
+fn rebuild() -> bool {
+    let mut command = Command::new("cargo");
+    command.arg("build");
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    if allocation::AVAILABLE {
+        command.args(["--features", "memory"]);
+    }
+
+    eprintln!("Sources changed, rebuilding");
+
+    command.status().is_ok_and(|status| status.success())
+}
+
+/// Replaces this process with the rebuilt runner, keeping the arguments
+#[cfg(unix)]
+fn restart(executable: &Path) -> ExitCode {
+    use std::os::unix::process::CommandExt;
+
+    let error = Command::new(executable).args(env::args_os().skip(1)).exec();
+    eprintln!("Could not restart the runner: {error}");
+    ExitCode::FAILURE
+}
+
+/// Runs the rebuilt runner with the same arguments until it exits
+#[cfg(not(unix))]
+fn restart(executable: &Path) -> ExitCode {
+    match Command::new(executable).args(env::args_os().skip(1)).status() {
+        Ok(status) if status.success() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("Could not restart the runner: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,34 +1,53 @@
-use std::fs;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-use crate::solutions;
+solutions!{2015, 1, "Not Quite Lisp", Day1}
 
-solutions!{2015, 1}
+/// A trailing line break is not an instruction, any other character is rejected
+///
+/// ```
+/// # use aoc::{solver::Solver, y2015::day1::Day1};
+/// assert_eq!(3, Day1::part1(&Day1::parse("(()(()(\n").unwrap()));
+/// assert!(Day1::parse("(()x").is_err());
+/// ```
+pub struct Day1;
 
-pub fn get_input(file: &str) -> String {
-    fs::read_to_string(file).unwrap()
-}
+impl Solver for Day1 {
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-pub fn solve_first(input: &str) -> i32 {
-    let down = input.chars().filter(|c| *c == ')').count();
-    let up = input.chars().count() - down;
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input.trim_end_matches(['\r', '\n']);
 
-    (up - down) as i32
-}
+        if !input.chars().all(|c| c == '(' || c == ')') {
+            return Err(ParseError::Malformed("The instructions may only contain ( and )"));
+        }
 
-pub fn solve_second(input: &str) -> i32 {
-    let mut current = 0;
+        Ok(input.to_string())
+    }
 
-    for (i, c) in input.chars().enumerate() {
-        match c {
-            '(' => current += 1,
-            ')' => current -= 1,
-            _ => panic!()
-        }
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let down = input.chars().filter(|c| *c == ')').count();
+        let up = input.chars().count() - down;
 
-        if current == -1 {
-            return i as i32 + 1;
-        }
+        (up - down) as i32
     }
 
-    panic!();
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut current = 0;
+
+        for (i, c) in input.chars().enumerate() {
+            match c {
+                '(' => current += 1,
+                ')' => current -= 1,
+                _ => panic!()
+            }
+
+            if current == -1 {
+                return i as i32 + 1;
+            }
+        }
+
+        panic!();
+    }
 }
@@ -1,36 +1,49 @@
-use std::{cmp::min, fs};
+use std::cmp::min;
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2015, 2}
+solutions!{2015, 2, "I Was Told There Would Be No Math", Day2}
 
-pub fn get_input(file: &str) -> Vec<(i32, i32, i32)> {
-    fs::read_to_string(file).unwrap().lines().map(|l| l.split_terminator('x').map(|i| i.parse().unwrap()).collect::<Vec<i32>>()).map(|v| (v[0], v[1], v[2])).collect()
-}
+pub struct Day2;
 
-pub fn solve_first(input: &Vec<(i32, i32, i32)>) -> i32 {
-    let mut sum = 0;
+impl Solver for Day2 {
+    type Input = Vec<(i32, i32, i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-    for present in input {
-        let first = present.0 * present.1;
-        let second = present.0 * present.2;
-        let third = present.1 * present.2;
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|l| {
+            let mut dimensions = l.split_terminator('x').map(|i| i.parse());
+            let mut next = || dimensions.next().ok_or(ParseError::Malformed("A present needs three dimensions"));
 
-        sum += 2 * first + 2 * second + 2 * third + min(min(first, second), third);
+            Ok((next()??, next()??, next()??))
+        }).collect()
     }
 
-    sum
-}
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut sum = 0;
 
-pub fn solve_second(input: &Vec<(i32, i32, i32)>) -> i32 {
-    let mut sum = 0;
+        for present in input {
+            let first = present.0 * present.1;
+            let second = present.0 * present.2;
+            let third = present.1 * present.2;
 
-    for present in input {
-        let mut ordered = [present.0, present.1, present.2];
-        ordered.sort();
+            sum += 2 * first + 2 * second + 2 * third + min(min(first, second), third);
+        }
 
-        sum += present.0 * present.1 * present.2 + 2 * ordered[0] + 2 * ordered[1];
+        sum
     }
 
-    sum
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut sum = 0;
+
+        for present in input {
+            let mut ordered = [present.0, present.1, present.2];
+            ordered.sort();
+
+            sum += present.0 * present.1 * present.2 + 2 * ordered[0] + 2 * ordered[1];
+        }
+
+        sum
+    }
 }
@@ -1,67 +1,88 @@
-use std::{collections::HashSet, fs};
+use std::collections::HashSet;
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2015, 3}
+solutions!{2015, 3, "Perfectly Spherical Houses in a Vacuum", Day3}
 
-pub fn get_input(file: &str) -> String {
-    fs::read_to_string(file).unwrap()
-}
+/// A trailing line break is not a direction, any other character is rejected
+///
+/// ```
+/// # use aoc::{solver::Solver, y2015::day3::Day3};
+/// assert_eq!(4, Day3::part1(&Day3::parse("^>v<\n").unwrap()));
+/// assert!(Day3::parse("^> v<").is_err());
+/// ```
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn solve_first(input: &str) -> usize {
-    let mut visited = HashSet::new();
-    visited.insert((0, 0));
-
-    let mut current = (0, 0);
-    for char in input.chars() {
-        match char {
-            '^' => current = (current.0, current.1 + 1),
-            '<' => current = (current.0 - 1, current.1),
-            'v' => current = (current.0, current.1 - 1),
-            '>' => current = (current.0 + 1, current.1),
-            c => panic!("{c} is not defined")
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input.trim_end_matches(['\r', '\n']);
+
+        if !input.chars().all(|c| matches!(c, '^' | '<' | 'v' | '>')) {
+            return Err(ParseError::Malformed("The directions may only contain ^, <, v and >"));
         }
 
-        visited.insert(current);
+        Ok(input.to_string())
     }
 
-    visited.len()
-}
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut visited = HashSet::new();
+        visited.insert((0, 0));
 
-pub fn solve_second(input: &str) -> usize {
-    let mut visited = HashSet::new();
-    visited.insert((0, 0));
-
-    let mut current = (0, 0);
-    let mut current2 = (0, 0);
-    let mut iter = input.chars();
-    while let Some(char) = iter.next() {
-
-        match char {
-            '^' => current = (current.0, current.1 + 1),
-            '<' => current = (current.0 - 1, current.1),
-            'v' => current = (current.0, current.1 - 1),
-            '>' => current = (current.0 + 1, current.1),
-            c => panic!("{c} is not defined")
+        let mut current = (0, 0);
+        for char in input.chars() {
+            match char {
+                '^' => current = (current.0, current.1 + 1),
+                '<' => current = (current.0 - 1, current.1),
+                'v' => current = (current.0, current.1 - 1),
+                '>' => current = (current.0 + 1, current.1),
+                c => panic!("{c} is not defined")
+            }
+
+            visited.insert(current);
         }
 
-        visited.insert(current);
+        visited.len()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut visited = HashSet::new();
+        visited.insert((0, 0));
 
-        let char = match iter.next() {
-            Some(c) => c,
-            None => break
-        };
+        let mut current = (0, 0);
+        let mut current2 = (0, 0);
+        let mut iter = input.chars();
+        while let Some(char) = iter.next() {
 
-        match char {
-            '^' => current2 = (current2.0, current2.1 + 1),
-            '<' => current2 = (current2.0 - 1, current2.1),
-            'v' => current2 = (current2.0, current2.1 - 1),
-            '>' => current2 = (current2.0 + 1, current2.1),
-            c => panic!("{c} is not defined")
+            match char {
+                '^' => current = (current.0, current.1 + 1),
+                '<' => current = (current.0 - 1, current.1),
+                'v' => current = (current.0, current.1 - 1),
+                '>' => current = (current.0 + 1, current.1),
+                c => panic!("{c} is not defined")
+            }
+
+            visited.insert(current);
+
+            let char = match iter.next() {
+                Some(c) => c,
+                None => break
+            };
+
+            match char {
+                '^' => current2 = (current2.0, current2.1 + 1),
+                '<' => current2 = (current2.0 - 1, current2.1),
+                'v' => current2 = (current2.0, current2.1 - 1),
+                '>' => current2 = (current2.0 + 1, current2.1),
+                c => panic!("{c} is not defined")
+            }
+
+            visited.insert(current2);
         }
 
-        visited.insert(current2);
+        visited.len()
     }
-
-    visited.len()
-}
\ No newline at end of file
+}
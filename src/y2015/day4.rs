@@ -1,39 +1,59 @@
-use std::fs;
-
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
+
+solutions!{2015, 4, "The Ideal Stocking Stuffer", Day4}
+
+/// The trailing line break is not part of the secret key
+///
+/// ```
+/// # use aoc::{solver::Solver, y2015::day4::Day4};
+/// assert_eq!("abcdef", Day4::parse("abcdef\n").unwrap());
+/// assert!(Day4::parse("abc def").is_err());
+/// assert!(Day4::parse("\n").is_err());
+/// ```
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input.trim_end_matches(['\r', '\n']);
+
+        if input.is_empty() || !input.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(ParseError::Malformed("The secret key must consist of letters and digits"));
+        }
 
-solutions!{2015, 4}
+        Ok(input.to_string())
+    }
 
-pub fn get_input(file: &str) -> String {
-    fs::read_to_string(file).unwrap()
-}
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut i = 0;
 
-pub fn solve_first(input: &str) -> usize {
-    let mut i = 0;
+        loop {
+            let test = format!("{}{}", input, i);
+            let hash = md5::compute(test);
 
-    loop {
-        let test = format!("{}{}", input, i);
-        let hash = md5::compute(test);
+            if hash[0] == 0 && hash[1] == 0 && (hash[2] & 0b11110000) == 0 {
+                return i;  
+            }
 
-        if hash[0] == 0 && hash[1] == 0 && (hash[2] & 0b11110000) == 0 {
-            return i;  
+            i += 1;
         }
-
-        i += 1;
     }
-}
 
-pub fn solve_second(input: &str) -> usize {
-    let mut i = 0;
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut i = 0;
 
-    loop {
-        let test = format!("{}{}", input, i);
-        let hash = md5::compute(test);
+        loop {
+            let test = format!("{}{}", input, i);
+            let hash = md5::compute(test);
 
-        if hash[0] == 0 && hash[1] == 0 && hash[2] == 0 {
-            return i;  
-        }
+            if hash[0] == 0 && hash[1] == 0 && hash[2] == 0 {
+                return i;  
+            }
 
-        i += 1;
+            i += 1;
+        }
     }
 }
@@ -1,14 +1,8 @@
-use std::fs;
-
 use rustc_hash::{FxBuildHasher, FxHashSet};
 
-use crate::solutions;
-
-solutions!{2015, 5}
+use crate::{error::ParseError, solutions, solver::Solver};
 
-pub fn get_input(file: &str) -> Vec<String> {
-    fs::read_to_string(file).unwrap().lines().map(|s| s.to_string()).collect()
-}
+solutions!{2015, 5, "Doesn't He Have Intern-Elves For This?", Day5}
 
 #[inline(always)]
 fn is_vowel(c: char) -> bool {
@@ -51,10 +45,6 @@ fn is_nice(input: &str) -> bool {
     vowels >= 3 && double
 }
 
-pub fn solve_first(input: &[String]) -> usize {
-    input.iter().filter(|s| is_nice(s)).count()
-}
-
 fn is_nice2(input: &str) -> bool {
     let charlist = input.chars().collect::<Vec<char>>();
     let mut pairs: FxHashSet<(char, char)> = FxHashSet::with_capacity_and_hasher(32, FxBuildHasher);
@@ -84,6 +74,22 @@ fn is_nice2(input: &str) -> bool {
     false
 }
 
-pub fn solve_second(input: &[String]) -> usize {
-    input.iter().filter(|s| is_nice2(s)).count()
-}
\ No newline at end of file
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter().filter(|s| is_nice(s)).count()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.iter().filter(|s| is_nice2(s)).count()
+    }
+}
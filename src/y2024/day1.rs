@@ -1,67 +1,73 @@
-use std::fs;
-
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2024, 1}
+solutions!{2024, 1, "Historian Hysteria", Day1}
 
 type List = (Vec<u32>, Vec<u32>);
 
-/// Parses lines containing two numbers seperated by whitespace,
-///    into two lists: One left list and one right list
-fn get_input(file: &str) -> List {
-    let content = fs::read_to_string(file).expect("The input file is missing");
+pub struct Day1;
 
-    let (left, right): List = content
-        .lines()
-        .map(|line| line.split_once("   ").expect("The line is not splittable by whitespace"))
-        .map(|(left_number, right_number)| (
-            left_number.parse::<u32>().expect("Must be number"), 
-            right_number.parse::<u32>().expect("Must be a number")
-        )).unzip();
+impl Solver for Day1 {
+    type Input = List;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    (left, right)
-}
+    /// Parses lines containing two numbers seperated by whitespace,
+    ///    into two lists: One left list and one right list
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
 
-/// ### Total List Distance
-/// 
-/// Provided two lists of numbers,
-///   sort both lists and then calculate the pairwise absolute difference
-/// The sum of all differences is the total distance of the two lists
-/// 
-fn solve_first(input: &List) -> u32 {
-    let (mut left, mut right) = input.clone();
-
-    left.sort();
-    right.sort();
-
-    left.iter()
-        .zip(right.iter())
-        .map(|(first, second)| first.abs_diff(*second))
-        .sum()
-}
+        for line in input.lines() {
+            let (left_number, right_number) = line.split_once("   ").ok_or(ParseError::Malformed("The line is not splittable by whitespace"))?;
 
-/// ### List Similarity Score
-/// 
-/// Calculate how often each number from the left list appears in the right list (its frequency)
-/// Then sum all numbers from the left list multiplied by their respective frequency
-/// 
-fn solve_second(input: &List) -> u32 {
-    let (left, right) = input;
-    
-    let mut frequencies: FxHashMap<u32, u8> = FxHashMap::with_capacity_and_hasher(1000, FxBuildHasher);
-
-    // First calculate the frequencies of all numbers in the right list 
-    for num in right {
-        match frequencies.get_mut(num) {
-            Some(count) => { *count += 1; },
-            None => { frequencies.insert(*num, 1); }
+            left.push(left_number.parse::<u32>()?);
+            right.push(right_number.parse::<u32>()?);
         }
+
+        Ok((left, right))
     }
 
-    // Then look up the frequencies of the right list for the numbers in the left list
-    left.iter()
-        .map(|num| num * *frequencies.get(num).unwrap_or(&0) as u32)
-        .sum()
+    /// ### Total List Distance
+    /// 
+    /// Provided two lists of numbers,
+    ///   sort both lists and then calculate the pairwise absolute difference
+    /// The sum of all differences is the total distance of the two lists
+    /// 
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (mut left, mut right) = input.clone();
+
+        left.sort();
+        right.sort();
+
+        left.iter()
+            .zip(right.iter())
+            .map(|(first, second)| first.abs_diff(*second))
+            .sum()
+    }
+
+    /// ### List Similarity Score
+    /// 
+    /// Calculate how often each number from the left list appears in the right list (its frequency)
+    /// Then sum all numbers from the left list multiplied by their respective frequency
+    /// 
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (left, right) = input;
+
+        let mut frequencies: FxHashMap<u32, u8> = FxHashMap::with_capacity_and_hasher(1000, FxBuildHasher);
+
+        // First calculate the frequencies of all numbers in the right list 
+        for num in right {
+            match frequencies.get_mut(num) {
+                Some(count) => { *count += 1; },
+                None => { frequencies.insert(*num, 1); }
+            }
+        }
+
+        // Then look up the frequencies of the right list for the numbers in the left list
+        left.iter()
+            .map(|num| num * *frequencies.get(num).unwrap_or(&0) as u32)
+            .sum()
+    }
 }
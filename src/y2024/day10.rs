@@ -1,32 +1,8 @@
-use std::fs;
-
 use rustc_hash::FxHashSet;
 
-use crate::solutions;
-
-solutions!{2024, 10}
-
-fn get_input(file: &str) -> Vec<(usize, usize)> {
-    let mut trail_heads = Vec::with_capacity(64);
-    let mut map = Vec::with_capacity(1024);
-
-    let mut width = 0;
-    let mut height = 0;
-
-    for (y, line) in fs::read_to_string(file).expect("No file there").lines().enumerate() {
-        width = line.len();
-        height += 1;
-        for (x, level) in line.chars().map(|character| character.to_digit(10).unwrap() as u8).enumerate() {
-            map.push(level);
-
-            if level == 0 {
-                trail_heads.push(y * width + x);
-            }
-        }
-    }
+use crate::{error::ParseError, solutions, solver::Solver};
 
-    get_trails(&map, &trail_heads, width, height)
-}
+solutions!{2024, 10, "Hoof It", Day10}
 
 /// Find all trails, where a trail is a path from 0 to 9 with one step increments
 /// trail_heads contains all 0 starting positions
@@ -38,8 +14,7 @@ fn get_trails(map: &[u8], trail_heads: &Vec<usize>, width: usize, height: usize)
     for tail_head in trail_heads {
         queue.push(*tail_head);
 
-        while !queue.is_empty() {
-            let current = queue.pop().unwrap();
+        while let Some(current) = queue.pop() {
             let value = map[current];
             let next_value = value + 1;
 
@@ -70,16 +45,47 @@ fn get_trails(map: &[u8], trail_heads: &Vec<usize>, width: usize, height: usize)
     trails
 }
 
-/// ### Unique Scoring
-/// 
-/// Calculate the sum of all trail heads' scores - the number of unique trails 
-fn solve_first(input: &[(usize, usize)]) -> usize {
-    FxHashSet::from_iter(input.iter()).len()
-}
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-/// ### Duplicate Scoring
-/// 
-/// Calculate the sum of all trail heads' scores - the number of trails
-fn solve_second(input: &[(usize, usize)]) -> usize {
-    input.len()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut trail_heads = Vec::with_capacity(64);
+        let mut map = Vec::with_capacity(1024);
+
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            width = line.len();
+            height += 1;
+            for (x, character) in line.chars().enumerate() {
+                let level = character.to_digit(10).ok_or(ParseError::Malformed("Heights must be digits"))? as u8;
+                map.push(level);
+
+                if level == 0 {
+                    trail_heads.push(y * width + x);
+                }
+            }
+        }
+
+        Ok(get_trails(&map, &trail_heads, width, height))
+    }
+
+    /// ### Unique Scoring
+    /// 
+    /// Calculate the sum of all trail heads' scores - the number of unique trails 
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        FxHashSet::from_iter(input.iter()).len()
+    }
+
+    /// ### Duplicate Scoring
+    /// 
+    /// Calculate the sum of all trail heads' scores - the number of trails
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.len()
+    }
 }
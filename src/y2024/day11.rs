@@ -1,18 +1,8 @@
-use std::fs;
-
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use crate::solutions;
-
-solutions!{2024, 11}
+use crate::{error::ParseError, solutions, solver::Solver};
 
-fn get_input(file: &str) -> FxHashMap<u64, usize> {
-    let input: Vec<u64> = fs::read_to_string(file).expect("No file there").split_whitespace().map(|s| s.parse().unwrap()).collect();
-
-    // Precalculate 25 Mutations to use later
-    let stones = stones_to_map(&input);
-    mutate_n_times::<25>(&stones)
-}
+solutions!{2024, 11, "Plutonian Pebbles", Day11}
 
 /// Tests if a number has an even amount of digits, and splits it into two
 /// 123 -> None
@@ -98,17 +88,33 @@ fn mutate_n_times<const N: usize>(stones: &FxHashMap<u64, usize>) -> FxHashMap<u
     new_stones
 }
 
-/// ### 25 Blinks
-/// 
-/// Count the number of stones after 25 blinks
-fn solve_first(input: &FxHashMap<u64, usize>) -> usize {
-    input.values().sum()
-}
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = FxHashMap<u64, usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers: Vec<u64> = input.split_whitespace().map(|s| s.parse()).collect::<Result<_, _>>()?;
 
-/// ### 75 Blinks
-/// 
-/// Count the number of stones after 75 blinks
-fn solve_second(input: &FxHashMap<u64, usize>) -> usize {
-    let stones = mutate_n_times::<50>(input);
-    stones.values().sum()
-}
\ No newline at end of file
+        // Precalculate 25 Mutations to use later
+        let stones = stones_to_map(&numbers);
+        Ok(mutate_n_times::<25>(&stones))
+    }
+
+    /// ### 25 Blinks
+    /// 
+    /// Count the number of stones after 25 blinks
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.values().sum()
+    }
+
+    /// ### 75 Blinks
+    /// 
+    /// Count the number of stones after 75 blinks
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let stones = mutate_n_times::<50>(input);
+        stones.values().sum()
+    }
+}
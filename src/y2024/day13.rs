@@ -1,8 +1,6 @@
-use std::fs;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-use crate::solutions;
-
-solutions!{2024, 13}
+solutions!{2024, 13, "Claw Contraption", Day13}
 
 const LEN_A: usize = "Button A: X+".len();
 const LEN_B: usize = "Button B: X+".len();
@@ -11,17 +9,6 @@ const LEN_P: usize = "Prize: X=".len();
 /// Holds the position of the A and B Buttons and the Price
 type MachineLayout = ((u64, u64), (u64, u64), (u64, u64)); 
 
-fn get_input(file: &str) -> Vec<MachineLayout> {
-    fs::read_to_string(file).expect("No file there").split_terminator("\n\n").map(|block| {
-        let split: Vec<&str> = block.split_terminator("\n").collect();
-        let (ax, ay) = split[0][LEN_A..].split_once(", Y+").unwrap();
-        let (bx, by) = split[1][LEN_B..].split_once(", Y+").unwrap();
-        let (px, py) = split[2][LEN_P..].split_once(", Y=").unwrap();
-
-        ((ax.parse().unwrap(), ay.parse().unwrap()), (bx.parse().unwrap(), by.parse().unwrap()), (px.parse().unwrap(), py.parse().unwrap()))
-    }).collect()
-}
-
 /// Because of floating point incaccuracy we have a small margin of error
 #[inline]
 fn is_integer(num: f64) -> bool {
@@ -69,22 +56,46 @@ fn calculate_cost<const B: bool, const O: u64>(layout: &MachineLayout) -> u64 {
     }
 }
 
-/// ### Hacking the slot machine
-/// 
-/// We use the algorithm described above with 0 offset and the 100 press threshold and sum the results 
-fn solve_first(input: &[MachineLayout]) -> u64 {
-    input.iter().map(|layout| {
-        calculate_cost::<true, 0>(layout)
-    }).sum()
-}
-
 const OFFSET: u64 = 10000000000000;
 
-/// ### Hacking the offset slot machine
-/// 
-/// We use the algorithm described above with a big offset and no press threshold and sum the results
-fn solve_second(input: &[MachineLayout]) -> u64 {
-    input.iter().map(|layout| {
-        calculate_cost::<false, OFFSET>(layout)
-    }).sum()
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<MachineLayout>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.split_terminator("\n\n").map(|block| {
+            let split: Vec<&str> = block.split_terminator("\n").collect();
+
+            if split.len() < 3 || split.iter().any(|line| line.len() < LEN_A) {
+                return Err(ParseError::Malformed("A machine needs two buttons and a prize"));
+            }
+
+            let (ax, ay) = split[0][LEN_A..].split_once(", Y+").ok_or(ParseError::Malformed("Invalid button A"))?;
+            let (bx, by) = split[1][LEN_B..].split_once(", Y+").ok_or(ParseError::Malformed("Invalid button B"))?;
+            let (px, py) = split[2][LEN_P..].split_once(", Y=").ok_or(ParseError::Malformed("Invalid prize"))?;
+
+            Ok(((ax.parse()?, ay.parse()?), (bx.parse()?, by.parse()?), (px.parse()?, py.parse()?)))
+        }).collect()
+    }
+
+    /// ### Hacking the slot machine
+    /// 
+    /// We use the algorithm described above with 0 offset and the 100 press threshold and sum the results 
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter().map(|layout| {
+            calculate_cost::<true, 0>(layout)
+        }).sum()
+    }
+
+    /// ### Hacking the offset slot machine
+    /// 
+    /// We use the algorithm described above with a big offset and no press threshold and sum the results
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.iter().map(|layout| {
+            calculate_cost::<false, OFFSET>(layout)
+        }).sum()
+    }
 }
@@ -1,14 +1,6 @@
-use std::fs;
+use crate::{error::ParseError, parameters::Parameters, solutions, solver::Solver};
 
-use crate::solutions;
-
-solutions!{2024, 14}
-
-const WIDTH: usize = 101;
-const HEIGHT: usize = 103;
-
-const MIDDLE_X: usize = (WIDTH + 1) / 2 - 1;
-const MIDDLE_Y: usize = (HEIGHT + 1) / 2 - 1;
+solutions!{2024, 14, "Restroom Redoubt", Day14, width = 101, height = 103}
 
 const TREE_THRESHOLD: f64 = 700.0;
 
@@ -16,10 +8,12 @@ const TREE_THRESHOLD: f64 = 700.0;
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 struct Robot((usize, usize), (isize, isize));
 
-// The map stores all the robots
+// The map stores all the robots and the size of the area they move in
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
-struct Map {
+pub struct Map {
     robots: Vec<Robot>,
+    width: usize,
+    height: usize,
 }
 
 impl Map {
@@ -28,8 +22,8 @@ impl Map {
     fn step<const N: isize>(&mut self) {
         for robot in &mut self.robots {
             robot.0 = (
-                (robot.0.0 as isize + robot.1.0 * N).rem_euclid(WIDTH as isize) as usize, 
-                (robot.0.1 as isize + robot.1.1 * N).rem_euclid(HEIGHT as isize) as usize
+                (robot.0.0 as isize + robot.1.0 * N).rem_euclid(self.width as isize) as usize, 
+                (robot.0.1 as isize + robot.1.1 * N).rem_euclid(self.height as isize) as usize
             );
         }
     }
@@ -38,19 +32,22 @@ impl Map {
     /// The score is the product of the number of robots in each quadrant
     /// The robots in the lines in the middle on any axis are ignored
     fn calculate_safety_score(&self) -> u64 {
+        let middle_x = self.width.div_ceil(2) - 1;
+        let middle_y = self.height.div_ceil(2) - 1;
+
         let mut tl = 0;
         let mut tr = 0;
         let mut bl = 0;
         let mut br = 0;
 
         for robot in self.robots.iter() {
-            if robot.0.0 < MIDDLE_X && robot.0.1 < MIDDLE_Y {
+            if robot.0.0 < middle_x && robot.0.1 < middle_y {
                 tl += 1;
-            } else if robot.0.0 > MIDDLE_X && robot.0.1 < MIDDLE_Y {
+            } else if robot.0.0 > middle_x && robot.0.1 < middle_y {
                 tr += 1;
-            } else if robot.0.0 < MIDDLE_X && robot.0.1 > MIDDLE_Y {
+            } else if robot.0.0 < middle_x && robot.0.1 > middle_y {
                 bl += 1;
-            } else if robot.0.0 > MIDDLE_X && robot.0.1 > MIDDLE_Y {
+            } else if robot.0.0 > middle_x && robot.0.1 > middle_y {
                 br += 1;
             }
         }
@@ -91,69 +88,92 @@ impl Map {
     }
 }
 
-fn get_input(file: &str) -> Map {
-    Map {
-        robots: fs::read_to_string(file).expect("No file there").lines().map(|l| {
-            let (position, velocity) = l[2..].split_once(" v=").unwrap();
+pub struct Day14;
 
-            let (px, py) = position.split_once(",").unwrap();
-            let (vx, vy) = velocity.split_once(",").unwrap();
+impl Solver for Day14 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-            Robot((px.parse().unwrap(), py.parse().unwrap()), (vx.parse().unwrap(), vy.parse().unwrap()))
-        }).collect()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Parameters::defaults(Self::PARAMETERS))
     }
-}
 
-/// ### Safety Score
-/// 
-/// Calculates the safety score after 100 steps
-fn solve_first(input: &Map) -> u64 {
-    let mut input = input.clone();
-    input.step::<100>();
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, ParseError> {
+        let width: usize = parameters.get("width")?;
+        let height: usize = parameters.get("height")?;
 
-    input.calculate_safety_score()
-}
-
-/// ### Drone Christmas Tree
-/// 
-/// The drones have repeating cycles of low variance with a frequency equal to the length of the dimension:
-/// In x direction -> width
-/// In y direction -> height
-/// We find both first spots and then calculate when the two frequencies will meet,
-/// this gives us the location of the christmas tree
-fn solve_second(input: &Map) -> u64 {
-    let mut input = input.clone();
-    let mut i: u64 = 0;
-
-    let mut x = 0;
-    let mut y = 0;
-
-    loop {
-        let (variance_x, variance_y) = input.variance();
-        if x == 0 && variance_x < TREE_THRESHOLD {
-            x = i;
+        if width == 0 || height == 0 {
+            return Err(ParseError::Malformed("The area must not be empty"));
         }
 
-        if y == 0 && variance_y < TREE_THRESHOLD {
-            y = i;
-        }
+        Ok(Map {
+            width,
+            height,
+            robots: input.lines().map(|l| {
+                let (position, velocity) = l.strip_prefix("p=")
+                    .and_then(|l| l.split_once(" v="))
+                    .ok_or(ParseError::Malformed("A robot needs a position and a velocity"))?;
 
-        if x > 0 && y > 0 {
-            if x < y {
-                x += WIDTH as u64;
-            }
+                let (px, py) = position.split_once(",").ok_or(ParseError::Malformed("Invalid position"))?;
+                let (vx, vy) = velocity.split_once(",").ok_or(ParseError::Malformed("Invalid velocity"))?;
+
+                Ok(Robot((px.parse()?, py.parse()?), (vx.parse()?, vy.parse()?)))
+            }).collect::<Result<_, ParseError>>()?
+        })
+    }
 
-            let mut diff = x - y;
+    /// ### Safety Score
+    /// 
+    /// Calculates the safety score after 100 steps
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut input = input.clone();
+        input.step::<100>();
 
-            if diff & 1 == 1 {
-                diff += WIDTH as u64;
+        input.calculate_safety_score()
+    }
+
+    /// ### Drone Christmas Tree
+    /// 
+    /// The drones have repeating cycles of low variance with a frequency equal to the length of the dimension:
+    /// In x direction -> width
+    /// In y direction -> height
+    /// We find both first spots and then calculate when the two frequencies will meet,
+    /// this gives us the location of the christmas tree
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut input = input.clone();
+        let mut i: u64 = 0;
+
+        let mut x = 0;
+        let mut y = 0;
+
+        loop {
+            let (variance_x, variance_y) = input.variance();
+            if x == 0 && variance_x < TREE_THRESHOLD {
+                x = i;
             }
 
-            return y + diff / 2 * HEIGHT as u64;
-        }
+            if y == 0 && variance_y < TREE_THRESHOLD {
+                y = i;
+            }
+
+            if x > 0 && y > 0 {
+                if x < y {
+                    x += input.width as u64;
+                }
 
-        i += 1;
+                let mut diff = x - y;
 
-        input.step::<1>();
+                if diff & 1 == 1 {
+                    diff += input.width as u64;
+                }
+
+                return y + diff / 2 * input.height as u64;
+            }
+
+            i += 1;
+
+            input.step::<1>();
+        }
     }
 }
@@ -1,37 +1,11 @@
-use std::{collections::VecDeque, fs::{self}};
+use std::collections::VecDeque;
 
-use crate::{solutions, util::flatgrid::FlatGrid};
+use crate::{error::ParseError, solutions, solver::Solver, util::{flatgrid::FlatGrid, grid::Grid}};
 
-solutions!{2024, 16}
+solutions!{2024, 16, "Reindeer Maze", Day16}
 
 type Maze = FlatGrid<isize, 141, 141>;
 
-/// The grid is represented by isizes. 0 means unexplored/start and isize::MIN means wall
-/// The grid is painted so that all tiles on the path between start and end have the current score
-fn get_input(file: &str) -> (Maze, usize, usize) {
-    let file = fs::read_to_string(file).expect("No file there");
-    
-    let mut maze = Maze::new();
-    let mut start = 0;
-    let mut end = 0;
-
-    for (y, line) in file.lines().enumerate() {
-        for (x, character) in line.chars().enumerate() {
-            maze.push(if character == '#' { isize::MIN } else { 0 });
-
-            if character == 'S' {
-                start = Maze::to_index(x, y);
-            } else if character == 'E' {
-                end = Maze::to_index(x, y);
-            }
-        }
-    }
-
-    paint_maze(&mut maze, start, end);
-
-    (maze, start, end)
-}
-
 /// Rotate the direction to the right
 #[inline(always)]
 fn right(direction: isize) -> isize {
@@ -116,65 +90,89 @@ fn paint_maze(maze: &mut Maze, start: usize, end: usize) {
     }
 }
 
-/// ### Final Score
-/// 
-/// Get the score at the end position and return it
-fn solve_first(input: &(Maze, usize, usize)) -> isize {
-    input.0[input.2]
-}
-
 const VISITED: isize = -2;
 
-/// ### Length of best Path Tiles
-/// 
-/// We step backwards the precalculated path and count all tiles that could form an optimal path
-fn solve_second(input: &(Maze, usize, usize)) -> usize {
-    let (maze, _, end) = input;
-    let mut maze = maze.clone();
-
-    let mut tiles = 0;
-
-    let mut queue = Vec::from([*end]);
-
-    while let Some(current_position) = queue.pop() {
-        let current_value = maze[current_position];
-
-        let right_value = maze[Maze::moved_horizontally(current_position, 1)];
-        let left_value = maze[Maze::moved_horizontally(current_position, -1)];
-        let top_value = maze[Maze::moved_vertically(current_position, 1)];
-        let bottom_value = maze[Maze::moved_vertically(current_position, -1)];
-
-        // The adjacent tile is part of the path, if it is one less, 1001 less or 999 more and the opposite tile has already been visited
-        // 5 4 3 2 1
-        //
-        // 1005 1004
-        //         3
-        //         2
-        //
-        // 4005 3004 4003 3002
-        //      3003      3001
-        //      2002 2001 2000
-        if right_value == current_value - 1 || right_value == current_value - 1001 || (right_value == current_value + 999 && left_value == VISITED) {
-            queue.push(Maze::moved_horizontally(current_position, 1));
-        }
+/// The maze painted with the scores of the best paths from the start, and where it ends
+pub struct ScoredMaze {
+    maze: Maze,
+    end: usize,
+}
 
-        if left_value == current_value - 1 || left_value == current_value - 1001 || (left_value == current_value + 999 && right_value == VISITED) {
-            queue.push(Maze::moved_horizontally(current_position, -1));
-        }
+pub struct Day16;
 
-        if top_value == current_value - 1 || top_value == current_value - 1001 || (top_value == current_value + 999 && bottom_value == VISITED) {
-            queue.push(Maze::moved_vertically(current_position, 1));
-        }
+impl Solver for Day16 {
+    type Input = ScoredMaze;
+    type Answer1 = isize;
+    type Answer2 = usize;
 
-        if bottom_value == current_value - 1 || bottom_value == current_value - 1001 || (bottom_value == current_value + 999 && top_value == VISITED) {
-            queue.push(Maze::moved_vertically(current_position, -1));
-        }
+    /// The grid is represented by isizes. 0 means unexplored/start and isize::MIN means wall
+    /// The grid is painted so that all tiles on the path between start and end have the current score
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (mut maze, [start, end]) = Maze::parse_with_markers(input, ['S', 'E'], |character| if character == '#' { isize::MIN } else { 0 })?;
 
-        tiles += 1;
+        paint_maze(&mut maze, start, end);
 
-        // After processing the tile, we set it to visited as to not process it again
-        maze[current_position] = VISITED;
+        Ok(ScoredMaze { maze, end })
     }
 
-    tiles
-}
\ No newline at end of file
+    /// ### Final Score
+    /// 
+    /// Get the score at the end position and return it
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.maze[input.end]
+    }
+
+    /// ### Length of best Path Tiles
+    /// 
+    /// We step backwards the precalculated path and count all tiles that could form an optimal path
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let ScoredMaze { maze, end } = input;
+        let mut maze = maze.clone();
+
+        let mut tiles = 0;
+
+        let mut queue = Vec::from([*end]);
+
+        while let Some(current_position) = queue.pop() {
+            let current_value = maze[current_position];
+
+            let right_value = maze[Maze::moved_horizontally(current_position, 1)];
+            let left_value = maze[Maze::moved_horizontally(current_position, -1)];
+            let top_value = maze[Maze::moved_vertically(current_position, 1)];
+            let bottom_value = maze[Maze::moved_vertically(current_position, -1)];
+
+            // The adjacent tile is part of the path, if it is one less, 1001 less or 999 more and the opposite tile has already been visited
+            // 5 4 3 2 1
+            //
+            // 1005 1004
+            //         3
+            //         2
+            //
+            // 4005 3004 4003 3002
+            //      3003      3001
+            //      2002 2001 2000
+            if right_value == current_value - 1 || right_value == current_value - 1001 || (right_value == current_value + 999 && left_value == VISITED) {
+                queue.push(Maze::moved_horizontally(current_position, 1));
+            }
+
+            if left_value == current_value - 1 || left_value == current_value - 1001 || (left_value == current_value + 999 && right_value == VISITED) {
+                queue.push(Maze::moved_horizontally(current_position, -1));
+            }
+
+            if top_value == current_value - 1 || top_value == current_value - 1001 || (top_value == current_value + 999 && bottom_value == VISITED) {
+                queue.push(Maze::moved_vertically(current_position, 1));
+            }
+
+            if bottom_value == current_value - 1 || bottom_value == current_value - 1001 || (bottom_value == current_value + 999 && top_value == VISITED) {
+                queue.push(Maze::moved_vertically(current_position, -1));
+            }
+
+            tiles += 1;
+
+            // After processing the tile, we set it to visited as to not process it again
+            maze[current_position] = VISITED;
+        }
+
+        tiles
+    }
+}
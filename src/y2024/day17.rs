@@ -1,30 +1,12 @@
 //! Design Choice: The instructions are saved in a u64 even if they can only be between 0 and 8
 //! However, using u64 over u8 results in 32% better performace
-use std::fs::{self};
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2024, 17}
+solutions!{2024, 17, "Chronospatial Computer", Day17}
 
 const REGISTER_SKIP_LEN: usize = "Register _: ".len();
 const PROGRAM_SKIP_LEN: usize = "Program: ".len();
 
-fn get_input(file: &str) -> ((u64, u64, u64), Vec<u64>) {
-    let file = fs::read_to_string(file).expect("No file there");
-    let lines: Vec<&str> = file.lines().collect();
-
-    let a = lines[0][REGISTER_SKIP_LEN..].parse().unwrap();
-    let b = lines[1][REGISTER_SKIP_LEN..].parse().unwrap();
-    let c = lines[2][REGISTER_SKIP_LEN..].parse().unwrap();
-
-    let instructions = lines[4][PROGRAM_SKIP_LEN..]
-        .chars()
-        .step_by(2)
-        .map(|c| c as u64 - b'0' as u64)
-        .collect();
-
-    ((a, b, c), instructions)
-}
-
 /// Computes the combo value for the given value
 /// 0..=3 evaluate to the value itself
 /// Then 4, 5 and 6 correspond to the values in register a, b and c
@@ -39,48 +21,6 @@ fn combo(value: u64, a: u64, b: u64, c: u64) -> u64 {
     }
 }
 
-/// ### Program Evaluation
-/// 
-/// Runs through the entire program and returns the output
-/// The specs of the "virtual machine" can be found [here](https://adventofcode.com/2024/day/17)
-fn solve_first(input: &((u64, u64, u64), Vec<u64>)) -> String {
-    let ((mut a, mut b, mut c), instructions) = input;
-
-    // Ip is the Instruction Pointer and points to the next instruction to be executed
-    let mut ip = 0;
-    let mut output = String::new();
-
-    while ip < instructions.len() {
-        let instruction = instructions[ip];
-        let opcode = instructions[ip + 1];
-
-        ip += 2;
-
-        // Execute the instruction according to the specs
-        match instruction {
-            0 => a = a >> combo(opcode, a, b, c),
-            1 => b ^= opcode,
-            2 => b = combo(opcode, a, b, c) & 0b111,
-            3 => if a != 0 {
-                ip = opcode as usize
-            },
-            4 => b ^= c,
-            5 => {
-                let number = combo(opcode, a, b, c) & 0b111;
-                output.push((number as u8 + b'0') as char);
-                output.push(',');
-            },
-            6 => b = a >> combo(opcode, a, b, c),
-            7 => c = a >> combo(opcode, a, b, c),
-            _ => panic!("Unknown instruction {instruction}")
-        }
-    }
-
-    // Remove the last comma from the output
-    output.pop().unwrap();
-    output
-}
-
 /// Converts a set of instructions to a number
 fn bytes_num(bytes: &[u64]) -> u64 {
     let mut output: u64 = 0;
@@ -90,78 +30,176 @@ fn bytes_num(bytes: &[u64]) -> u64 {
     output
 }
 
-/// ### Replicating Program
-/// 
-/// Searches for the input value for register a, such that the program outputs itself
-/// A 3 bit set in the input only influences the same offset and lower of the output
-/// So we reconstruct the input 3 bits at a time, from highest to lowest
-fn solve_second(input: &((u64, u64, u64), Vec<u64>)) -> u64 {
-    let ((_, b_initial, c_initial), instructions) = input;
-    let target = bytes_num(instructions);
+/// The registers of the computer when it starts, and the program it runs
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u64>,
+}
 
-    let mut queue: Vec<(u64, u64)> = Vec::with_capacity(64);
+/// Programs that cannot be run are rejected while parsing
+///
+/// ```
+/// # use aoc::{solver::Solver, y2024::day17::Day17};
+/// let registers = "Register A: 729\nRegister B: 0\nRegister C: 0\n\n";
+///
+/// assert!(Day17::parse(&format!("{registers}Program: 0,1,5,4,3,0")).is_ok());
+/// assert!(Day17::parse(&format!("{registers}Program: ")).is_err());
+/// assert!(Day17::parse(&format!("{registers}Program: 0,1,5")).is_err());
+/// ```
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Computer;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+
+        if lines.len() < 5 || lines[..3].iter().any(|line| line.len() < REGISTER_SKIP_LEN) || lines[4].len() < PROGRAM_SKIP_LEN {
+            return Err(ParseError::Malformed("Expected three registers and a program"));
+        }
 
-    let mut register_a_seed = u64::MAX;
+        let a = lines[0][REGISTER_SKIP_LEN..].parse()?;
+        let b = lines[1][REGISTER_SKIP_LEN..].parse()?;
+        let c = lines[2][REGISTER_SKIP_LEN..].parse()?;
 
-    queue.push((0, instructions.len() as u64 - 1));
+        let instructions: Vec<u64> = lines[4][PROGRAM_SKIP_LEN..]
+            .chars()
+            .step_by(2)
+            .map(|c| c.to_digit(8).map(u64::from).ok_or(ParseError::Malformed("Instructions must be between 0 and 7")))
+            .collect::<Result<_, _>>()?;
 
-    while !queue.is_empty() {
-        let (start_seed, position) = queue.pop().unwrap();
+        // Every instruction is followed by its operand
+        if instructions.is_empty() || !instructions.len().is_multiple_of(2) {
+            return Err(ParseError::Malformed("The program must consist of pairs of instructions and operands"));
+        }
 
-        if start_seed > register_a_seed {
-            continue;
+        // Part 2 stores the program 3 bits per value in a u64
+        if instructions.len() > u64::BITS as usize / 3 {
+            return Err(ParseError::Malformed("The program must not have more than 21 values"));
         }
 
-        // We check all 3 bit combinations
-        for i in 0..8 {
-            let test_seed = start_seed | (i << (3 * position));
-
-            let mut a = test_seed;
-            let mut b = *b_initial;
-            let mut c = *c_initial;
-
-            let mut output = 0;
-            let mut output_len = 0;
-
-            let mut ip = 0;
-
-            while ip < instructions.len() {
-                let instruction = instructions[ip];
-                let opcode = instructions[ip + 1];
-
-                ip += 2;
-
-                match instruction {
-                    0 => a = a >> combo(opcode, a, b, c),
-                    1 => b ^= opcode,
-                    2 => b = combo(opcode, a, b, c) & 0b111,
-                    3 => if a != 0 {
-                        ip = opcode as usize
-                    },
-                    4 => b ^= c,
-                    5 => {
-                        // We save the output in a number for efficient comparision
-                        output |= (combo(opcode, a, b, c) & 0b111) << (output_len * 3);
-                        output_len += 1;
-                    },
-                    6 => b = a >> combo(opcode, a, b, c),
-                    7 => c = a >> combo(opcode, a, b, c),
-                    _ => panic!("Unknown instruction {instruction}")
-                }
+        Ok(Computer { a, b, c, program: instructions })
+    }
+
+    /// ### Program Evaluation
+    /// 
+    /// Runs through the entire program and returns the output
+    /// The specs of the "virtual machine" can be found [here](https://adventofcode.com/2024/day/17)
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let Computer { a, b, c, program: instructions } = input;
+        let (mut a, mut b, mut c) = (*a, *b, *c);
+
+        // Ip is the Instruction Pointer and points to the next instruction to be executed
+        let mut ip = 0;
+        let mut output = String::new();
+
+        while ip < instructions.len() {
+            let instruction = instructions[ip];
+            let opcode = instructions[ip + 1];
+
+            ip += 2;
+
+            // Execute the instruction according to the specs
+            match instruction {
+                0 => a = a >> combo(opcode, a, b, c),
+                1 => b ^= opcode,
+                2 => b = combo(opcode, a, b, c) & 0b111,
+                3 => if a != 0 {
+                    ip = opcode as usize
+                },
+                4 => b ^= c,
+                5 => {
+                    let number = combo(opcode, a, b, c) & 0b111;
+                    output.push((number as u8 + b'0') as char);
+                    output.push(',');
+                },
+                6 => b = a >> combo(opcode, a, b, c),
+                7 => c = a >> combo(opcode, a, b, c),
+                _ => panic!("Unknown instruction {instruction}")
+            }
+        }
+
+        // Remove the last comma from the output
+        output.pop().unwrap();
+        output
+    }
+
+    /// ### Replicating Program
+    /// 
+    /// Searches for the input value for register a, such that the program outputs itself
+    /// A 3 bit set in the input only influences the same offset and lower of the output
+    /// So we reconstruct the input 3 bits at a time, from highest to lowest
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let Computer { b: b_initial, c: c_initial, program: instructions, .. } = input;
+        let target = bytes_num(instructions);
+
+        let mut queue: Vec<(u64, u64)> = Vec::with_capacity(64);
+
+        let mut register_a_seed = u64::MAX;
+
+        queue.push((0, instructions.len() as u64 - 1));
+
+        while let Some((start_seed, position)) = queue.pop() {
+
+            if start_seed > register_a_seed {
+                continue;
             }
 
-            // The higher bits are already equal, so we can just compare the rest of the number without masking
-            if output >> (position * 3) == target >> (position * 3) {
-                if position == 0 {
-                    if test_seed < register_a_seed {
-                        register_a_seed = test_seed;
+            // We check all 3 bit combinations
+            for i in 0..8 {
+                let test_seed = start_seed | (i << (3 * position));
+
+                let mut a = test_seed;
+                let mut b = *b_initial;
+                let mut c = *c_initial;
+
+                let mut output = 0;
+                let mut output_len = 0;
+
+                let mut ip = 0;
+
+                while ip < instructions.len() {
+                    let instruction = instructions[ip];
+                    let opcode = instructions[ip + 1];
+
+                    ip += 2;
+
+                    match instruction {
+                        0 => a = a >> combo(opcode, a, b, c),
+                        1 => b ^= opcode,
+                        2 => b = combo(opcode, a, b, c) & 0b111,
+                        3 => if a != 0 {
+                            ip = opcode as usize
+                        },
+                        4 => b ^= c,
+                        5 => {
+                            // We save the output in a number for efficient comparision
+                            output |= (combo(opcode, a, b, c) & 0b111) << (output_len * 3);
+                            output_len += 1;
+                        },
+                        6 => b = a >> combo(opcode, a, b, c),
+                        7 => c = a >> combo(opcode, a, b, c),
+                        _ => panic!("Unknown instruction {instruction}")
+                    }
+                }
+
+                // The higher bits are already equal, so we can just compare the rest of the number without masking
+                if output >> (position * 3) == target >> (position * 3) {
+                    if position == 0 {
+                        if test_seed < register_a_seed {
+                            register_a_seed = test_seed;
+                        }
+                    } else {
+                        queue.push((test_seed, position - 1));
                     }
-                } else {
-                    queue.push((test_seed, position - 1));
                 }
             }
         }
-    }
 
-    register_a_seed
+        register_a_seed
+    }
 }
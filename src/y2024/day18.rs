@@ -1,33 +1,38 @@
-use std::{collections::VecDeque, fs};
+use std::collections::VecDeque;
 
-use crate::{solutions, util::flatgrid::FlatGrid};
+use crate::{error::ParseError, parameters::Parameters, solutions, solver::Solver, util::flatgrid::FlatGrid};
 
-solutions!{2024, 18}
+solutions!{2024, 18, "RAM Run", Day18, size = 71, bytes = 1024}
 
-const WIDTH: usize = 71;
-const HEIGHT: usize = 71;
+const MAX_SIZE: usize = 71;
 
-type MemorySpace = FlatGrid<i16, WIDTH, HEIGHT>;
+type MemorySpace = FlatGrid<i16, MAX_SIZE, MAX_SIZE>;
 
-fn get_input(file: &str) -> Vec<(u8, u8)> {
-    fs::read_to_string(file)
-        .expect("No file there")
-        .lines()
-        .map(|l| l.split_once(",").unwrap())
-        .map(|(l, r)| (l.parse().unwrap(), r.parse().unwrap()))
-        .collect()
+/// The falling bytes, the size of the memory space and how many bytes have fallen in the first part
+pub struct Memory {
+    bytes: Vec<(u8, u8)>,
+    size: usize,
+    fallen: usize,
 }
 
 // Calculates the required steps to go from the top left to the bottom right after n bytes have fallen
 // The entire memory space is walked, setting each squares value to the number of steps taken
-fn steps_after(n: usize, input: &[(u8, u8)]) -> i16
+fn steps_after(n: usize, memory: &Memory) -> i16
 {
     let mut space = MemorySpace::filled(i16::MAX);
     space[0] = 0;
+
+    // A smaller memory space is placed in the top left, the rest of the grid is corrupted
+    for index in 0..MemorySpace::area() {
+        let (x, y) = MemorySpace::to_coordinates(index);
+        if x >= memory.size || y >= memory.size {
+            space[index] = -1;
+        }
+    }
     
     // Setting the corrupted regions to -1 ensures the walking algorithm never goes there
     // as it only visits spots with a higher score than the current score
-    for &(x, y) in input.iter().take(n) {
+    for &(x, y) in memory.bytes.iter().take(n) {
         space[MemorySpace::to_index(x as usize, y as usize)] = -1;
     }
 
@@ -38,62 +43,74 @@ fn steps_after(n: usize, input: &[(u8, u8)]) -> i16
         let current_value = space[current_index];
         let next_value = current_value + 1;
 
-        if !MemorySpace::will_horizontal_move_cross_border(current_index, 1) {
-            let right = MemorySpace::moved_horizontally(current_index, 1);
-            if space[right] > next_value {
-                space[right] = next_value;
-                queue.push_back(right);
+        for (neighbor, _) in MemorySpace::neighbors4(current_index) {
+            if space[neighbor] > next_value {
+                space[neighbor] = next_value;
+                queue.push_back(neighbor);
             }
         }
+    }
 
-        if !MemorySpace::will_horizontal_move_cross_border(current_index, -1) {
-            let left = MemorySpace::moved_horizontally(current_index, -1);
-            if space[left] > next_value {
-                space[left] = next_value;
-                queue.push_back(left);
-            }
-        }
+    space[MemorySpace::to_index(memory.size - 1, memory.size - 1)]
+}
 
-        if !MemorySpace::will_vertical_move_cross_border(current_index, 1) {
-            let top = MemorySpace::moved_vertically(current_index, 1);
-            if space[top] > next_value {
-                space[top] = next_value;
-                queue.push_back(top);
-            }
-        }
-        
+pub struct Day18;
 
-        if !MemorySpace::will_vertical_move_cross_border(current_index, -1) {
-            let bottom = MemorySpace::moved_vertically(current_index, -1);
-            if space[bottom] > next_value {
-                space[bottom] = next_value;
-                queue.push_back(bottom);
-            }
-        }
+impl Solver for Day18 {
+    type Input = Memory;
+    type Answer1 = i16;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Parameters::defaults(Self::PARAMETERS))
     }
 
-    space[MemorySpace::last_index()]
-}
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, ParseError> {
+        let size: usize = parameters.get("size")?;
+        let fallen = parameters.get("bytes")?;
 
-/// ### Steps after one Kilobyte
-/// 
-/// Calculates the number of steps needed to reach the bottom right 
-/// after 1024 bytes have fallen
-fn solve_first(input: &[(u8, u8)]) -> i16 {
-    steps_after(1024, input)
-}
+        if size == 0 || size > MAX_SIZE {
+            return Err(ParseError::Malformed("The memory space must be between 1x1 and 71x71"));
+        }
 
-/// ### Last byte to the finish
-/// 
-/// Calculate the last byte when the finish line is reachable
-/// The next byte blocks the path, indicated by needing i16::MAX steps
-fn solve_second(input: &[(u8, u8)]) -> String {
-    let first_blocked_index = (0..input.len())
-        .map(|bytes_fallen| move || steps_after(bytes_fallen, input))
-        .collect::<Vec<_>>()
-        .partition_point(|solve_after| solve_after() != i16::MAX);
+        let bytes = input
+            .lines()
+            .map(|l| l.split_once(",").ok_or(ParseError::Malformed("A byte needs two coordinates")))
+            .map(|split| {
+                let (l, r) = split?;
+                let byte: (u8, u8) = (l.parse()?, r.parse()?);
 
-    let coord = input[first_blocked_index - 1];
+                if byte.0 as usize >= size || byte.1 as usize >= size {
+                    return Err(ParseError::Malformed("A byte falls outside of the memory space"));
+                }
+
+                Ok(byte)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Memory { bytes, size, fallen })
+    }
 
-    format!("{},{}", coord.0, coord.1)
+    /// ### Steps after one Kilobyte
+    /// 
+    /// Calculates the number of steps needed to reach the bottom right 
+    /// after 1024 bytes have fallen
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        steps_after(input.fallen, input)
+    }
+
+    /// ### Last byte to the finish
+    /// 
+    /// Calculate the last byte when the finish line is reachable
+    /// The next byte blocks the path, indicated by needing i16::MAX steps
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let first_blocked_index = (0..input.bytes.len())
+            .map(|bytes_fallen| move || steps_after(bytes_fallen, input))
+            .collect::<Vec<_>>()
+            .partition_point(|solve_after| solve_after() != i16::MAX);
+
+        let coord = input.bytes[first_blocked_index - 1];
+
+        format!("{},{}", coord.0, coord.1)
+    }
 }
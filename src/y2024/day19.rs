@@ -1,10 +1,8 @@
-use std::fs;
-
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2024, 19}
+solutions!{2024, 19, "Linen Layout", Day19}
 
 const WHITE: u8 = 0;
 const BLUE: u8 = 1;
@@ -14,38 +12,17 @@ const GREEN: u8 = 4;
 
 type Towels = [Vec<Vec<u8>>; 5];
 
-fn string_to_num(pattern: &str) -> Vec<u8> {
+fn string_to_num(pattern: &str) -> Result<Vec<u8>, ParseError> {
     pattern.chars().map(|c| match c {
-        'w' => WHITE,
-        'u' => BLUE,
-        'b' => BLACK,
-        'r' => RED,
-        'g' => GREEN,
-        _ => panic!("Unkown char {c}")
+        'w' => Ok(WHITE),
+        'u' => Ok(BLUE),
+        'b' => Ok(BLACK),
+        'r' => Ok(RED),
+        'g' => Ok(GREEN),
+        _ => Err(ParseError::Malformed("Unknown stripe color"))
     }).collect()
 }
 
-fn get_input(file: &str) -> (Towels, Vec<Vec<u8>>) {
-    let file = fs::read_to_string(file).expect("No file there");
-    let (first, second) = file.split_once("\n\n").unwrap();
-
-    let mut towels: Towels = Default::default();
-
-    for pattern in first.split(", ").map(&string_to_num) {
-        towels[pattern[0] as usize].push(pattern);
-    }
-
-    let possible_patterns = second.lines()
-        .map(&string_to_num)
-        .filter(|target| is_possible(target, &towels))
-        .collect();
-
-    (
-        towels,
-        possible_patterns
-    )
-}
-
 /// Checks if a pattern is possible
 /// Only Towels that have the first stripe correct are checked if they match
 /// For each towel, check if the towel isnt longer than the target pattern
@@ -66,13 +43,6 @@ fn is_possible(target: &[u8], towels: &Towels) -> bool {
     false
 }
 
-/// ### Possible Patterns
-/// Counts how many patterns are possible to produce from the towels
-/// The solution is precomputed by the input and shared among both solutions 
-fn solve_first(input: &(Towels, Vec<Vec<u8>>)) -> usize {
-    input.1.len()
-}
-
 /// Calculates how many possibilities there are to produce the target pattern from the given towels
 /// The number of possibilities is how many paths lead to a the target and towel being equal
 fn possibilities<'a>(target: &'a [u8], towels: &Towels, cache: &mut FxHashMap<&'a[u8], u64>) -> u64 {
@@ -95,11 +65,56 @@ fn possibilities<'a>(target: &'a [u8], towels: &Towels, cache: &mut FxHashMap<&'
     sum_of_possibilities
 }
 
-/// ### Possibilities for Patterns
-/// Calculates how many possibilities there are to build all patterns
-/// Only known to be possible patterns are tested
-fn solve_second(input: &(Towels, Vec<Vec<u8>>)) -> u64 {
-    input.1.iter()
-        .map(|target| possibilities(target, &input.0, &mut FxHashMap::with_capacity_and_hasher(192, FxBuildHasher)))
-        .sum()
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = (Towels, Vec<Vec<u8>>);
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (first, second) = input.split_once("\n\n").ok_or(ParseError::Malformed("Towels and designs must be seperated by an empty line"))?;
+
+        let mut towels: Towels = Default::default();
+
+        for pattern in first.split(", ").map(&string_to_num) {
+            let pattern = pattern?;
+            let Some(&first_stripe) = pattern.first() else {
+                return Err(ParseError::Malformed("A towel needs at least one stripe"));
+            };
+
+            towels[first_stripe as usize].push(pattern);
+        }
+
+        let mut possible_patterns = Vec::new();
+
+        for target in second.lines().map(&string_to_num) {
+            let target = target?;
+
+            if is_possible(&target, &towels) {
+                possible_patterns.push(target);
+            }
+        }
+
+        Ok((
+            towels,
+            possible_patterns
+        ))
+    }
+
+    /// ### Possible Patterns
+    /// Counts how many patterns are possible to produce from the towels
+    /// The solution is precomputed by the input and shared among both solutions 
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.1.len()
+    }
+
+    /// ### Possibilities for Patterns
+    /// Calculates how many possibilities there are to build all patterns
+    /// Only known to be possible patterns are tested
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.1.iter()
+            .map(|target| possibilities(target, &input.0, &mut FxHashMap::with_capacity_and_hasher(192, FxBuildHasher)))
+            .sum()
+    }
 }
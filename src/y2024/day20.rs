@@ -1,8 +1,6 @@
-use std::fs;
+use crate::{error::ParseError, parameters::Parameters, solutions, solver::Solver, util::flatgrid::FlatGrid};
 
-use crate::{solutions, util::flatgrid::FlatGrid};
-
-solutions!{2024, 20}
+solutions!{2024, 20, "Race Condition", Day20, min_save = 100, cheat_distance = 20}
 
 type Maze = FlatGrid<u16, 141, 141>;
 
@@ -12,174 +10,222 @@ const FINISH_CHAR: char = 'E';
 const WALL: u16 = u16::MAX - 1;
 const AIR: u16 = u16::MAX;
 
-fn get_input(file: &str) -> (Maze, usize) {
-    let file = fs::read_to_string(file).expect("No file there");
-    
-    let mut maze: Maze = Maze::new();
-    let mut finish = 0;
-
-    for character in file.chars() {
-        if character == FINISH_CHAR {
-            finish = maze.len();
-        }
+const VISITED: u16 = 0;
 
-        if character != '\n' {
-            maze.push(if character == WALL_CHAR { WALL } else { AIR });
-        }
+/// The racetrack with its finish, and how long and how good a cheat must be
+pub struct Racetrack {
+    maze: Maze,
+    finish: usize,
+    /// The minimum time a cheat must save to be counted
+    min_save: usize,
+    /// The maximum distance of a cheat in the second part
+    cheat_distance: usize,
+}
 
+/// The parameters come from the user, so they are checked while parsing
+///
+/// ```
+/// # use aoc::{parameters::Parameters, solver::Solver, y2024::day20::Day20};
+/// let mut parameters = Parameters::defaults(Day20::PARAMETERS);
+/// assert!(Day20::parse_with("S.E", &parameters).is_ok());
+///
+/// parameters.set("min_save", 65534).unwrap();
+/// assert!(Day20::parse_with("S.E", &parameters).is_err());
+/// ```
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Racetrack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Parameters::defaults(Self::PARAMETERS))
     }
 
-    (maze, finish)
-}
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input, ParseError> {
+        // Smaller racetracks are placed in the top left, surrounded by walls
+        let mut maze: Maze = Maze::filled(WALL);
+        let mut finish = None;
 
-const MIN_SKIP_DISTANCE: usize = 100;
-const MIN_DISTANCE_DIFFERENCE: u16 = MIN_SKIP_DISTANCE as u16 + 2;
-
-/// ### Short Skips
-/// Finds all skips through a single wall where the time save is at least 100
-/// The algorithm starts at the finish and counts up the current distance, setting it to the maze
-/// It then checks the four possible jump locations to see if their distance is at least 102 less, 
-/// to also account for the distance travelled through the wall
-fn solve_first(input: &(Maze, usize)) -> usize {
-    let mut maze = input.0.clone();
-    let finish = input.1;
-
-    let mut distance = 0;
-    let mut good_cheats = 0;
-
-    // Start at the finish
-    let mut pos = finish;
-
-    loop {
-        // Set the current locations value to its distance from the finish
-        maze[pos] = distance;
-
-        // Only check when at least a good cheat away from the finish
-        if distance >= MIN_DISTANCE_DIFFERENCE {
-            // For each direction check if the cheat saves at least 100 picoseconds
-            if !Maze::will_horizontal_move_cross_border(pos, -2) && maze[Maze::moved_horizontally(pos, -2)] <= distance - MIN_DISTANCE_DIFFERENCE {
-                good_cheats += 1;
+        for (y, line) in input.lines().enumerate() {
+            if y >= Maze::height() || line.len() > Maze::width() {
+                return Err(ParseError::Malformed("The racetrack can be at most 141x141 tiles"));
             }
 
-            if !Maze::will_horizontal_move_cross_border(pos, 2) && maze[Maze::moved_horizontally(pos, 2)] <= distance - MIN_DISTANCE_DIFFERENCE {
-                good_cheats += 1;
-            }
+            for (x, character) in line.chars().enumerate() {
+                let index = Maze::to_index(x, y);
 
-            if !Maze::will_vertical_move_cross_border(pos, -2) && maze[Maze::moved_vertically(pos, -2)] <= distance - MIN_DISTANCE_DIFFERENCE {
-                good_cheats += 1;
-            }
+                if character == FINISH_CHAR {
+                    finish = Some(index);
+                }
 
-            if !Maze::will_vertical_move_cross_border(pos, 2) && maze[Maze::moved_vertically(pos, 2)] <= distance - MIN_DISTANCE_DIFFERENCE {
-                good_cheats += 1;
+                maze[index] = if character == WALL_CHAR { WALL } else { AIR };
             }
         }
 
-        distance += 1;
-
-        // Check where the next path tile is,
-        // if there is none we reached the start
-        if maze[Maze::moved_horizontally(pos, 1)] == AIR {
-            pos += 1;
-        } else if maze[Maze::moved_horizontally(pos, -1)] == AIR {
-            pos -= 1;
-        } else if maze[Maze::moved_vertically(pos, 1)] == AIR {
-            pos += Maze::width();
-        } else if maze[Maze::moved_vertically(pos, -1)] == AIR {
-            pos -= Maze::width();
-        } else {
-            break;
+        let min_save = parameters.get("min_save")?;
+
+        // No cheat can save more than the whole racetrack, which also keeps the distances of part 1 within a u16
+        if min_save > Maze::area() {
+            return Err(ParseError::Malformed("The minimum save cannot be longer than the racetrack"));
         }
+
+        Ok(Racetrack {
+            maze,
+            finish: finish.ok_or(ParseError::Malformed("The racetrack has no finish"))?,
+            min_save,
+            cheat_distance: parameters.get("cheat_distance")?,
+        })
     }
 
-    good_cheats
-}
+    /// ### Short Skips
+    /// Finds all skips through a single wall where the time save is at least 100
+    /// The algorithm starts at the finish and counts up the current distance, setting it to the maze
+    /// It then checks the four possible jump locations to see if their distance is at least 102 less, 
+    /// to also account for the distance travelled through the wall
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut maze = input.maze.clone();
+        let finish = input.finish;
 
-const VISITED: u16 = 0;
-const CHEAT_DISTANCE: usize = 20;
-
-/// ### Long Skips
-/// 
-/// Finds all cheats that travel at most 20 tiles and save at least 100 picoseconds
-/// The entire path is precalculated from finish to start like in part one
-/// Then the entire path is retraced, for each path tile the remaining path is scanned for skips
-fn solve_second(input: &(Maze, usize)) -> usize {
-    let mut maze = input.0.clone();
-    let finish = input.1;
-
-    let mut pos = finish;
-    let mut path: Vec<(usize, usize)> = Vec::with_capacity(Maze::area() / 4);
-
-    // Go through the maze and keep track of the path
-    loop {
-        maze[pos] = VISITED;
-
-        path.push(Maze::to_coordinates(pos));
-
-        if maze[Maze::moved_horizontally(pos, 1)] == AIR {
-            pos += 1;
-        } else if maze[Maze::moved_horizontally(pos, -1)] == AIR {
-            pos -= 1;
-        } else if maze[Maze::moved_vertically(pos, 1)] == AIR {
-            pos += Maze::width();
-        } else if maze[Maze::moved_vertically(pos, -1)] == AIR {
-            pos -= Maze::width();
-        } else {
-            // The start position has been found, as the path has ended
-            break;
+        let min_distance_difference = input.min_save as u16 + 2;
+
+        let mut distance = 0;
+        let mut good_cheats = 0;
+
+        // Start at the finish
+        let mut pos = finish;
+
+        loop {
+            // Set the current locations value to its distance from the finish
+            maze[pos] = distance;
+
+            // Only check when at least a good cheat away from the finish
+            if distance >= min_distance_difference {
+                // For each direction check if the cheat saves at least 100 picoseconds
+                if !Maze::will_horizontal_move_cross_border(pos, -2) && maze[Maze::moved_horizontally(pos, -2)] <= distance - min_distance_difference {
+                    good_cheats += 1;
+                }
+
+                if !Maze::will_horizontal_move_cross_border(pos, 2) && maze[Maze::moved_horizontally(pos, 2)] <= distance - min_distance_difference {
+                    good_cheats += 1;
+                }
+
+                if !Maze::will_vertical_move_cross_border(pos, -2) && maze[Maze::moved_vertically(pos, -2)] <= distance - min_distance_difference {
+                    good_cheats += 1;
+                }
+
+                if !Maze::will_vertical_move_cross_border(pos, 2) && maze[Maze::moved_vertically(pos, 2)] <= distance - min_distance_difference {
+                    good_cheats += 1;
+                }
+            }
+
+            distance += 1;
+
+            // Check where the next path tile is,
+            // if there is none we reached the start
+            if maze[Maze::moved_horizontally(pos, 1)] == AIR {
+                pos += 1;
+            } else if maze[Maze::moved_horizontally(pos, -1)] == AIR {
+                pos -= 1;
+            } else if maze[Maze::moved_vertically(pos, 1)] == AIR {
+                pos += Maze::width();
+            } else if maze[Maze::moved_vertically(pos, -1)] == AIR {
+                pos -= Maze::width();
+            } else {
+                break;
+            }
         }
+
+        good_cheats
     }
 
-    let mut good_cheats = 0;
-
-    // Iterate through the entire path, except the last 100, where a good skip from a location further up is no longer possible
-    // We walk through the path with a location where we skip to. For each location to skip to, we check the path from 100 tiles further up
-    // for valid locations to jump from
-    for (to_index, to_coords) in path.iter().enumerate().take(path.len() - MIN_SKIP_DISTANCE) {
-        let mut from_index = 0;
-
-        // The remaining path, where we can skip from, starts 100 tiles further up
-        let remaining_path = &path[to_index + MIN_SKIP_DISTANCE..];
-
-
-        while from_index < remaining_path.len() {
-            let from_coords = remaining_path[from_index];
-            // The distance of the two points is the manhattan distance
-            let distance = to_coords.0.abs_diff(from_coords.0) + to_coords.1.abs_diff(from_coords.1);
-
-            // If the distance between the points is greate than the index from the start of the remaining path,
-            // then we can't skip as the extra distance we need to walk puts us under 100 net save
-            if distance <= from_index {
-                // We can always safely move at least the distance to the outer end of the diamond area, that marks where cheats are possible
-                // From the inside we can be sure, that when we walk CHEAT_DISTANCE - distance + 1 tiles we will at most be on the rim of the area
-                // Thus we don't need to count all tiles individually, and instead add them in bulk
-                // When we are outside of the area we can walk at least CHEAT_DISTANCE - distance to end up at most right in front of the cheat area
-                if distance <= CHEAT_DISTANCE {
-                    // Safely walk inside the cheat area
-                    good_cheats += CHEAT_DISTANCE - distance + 1;
-                    from_index += CHEAT_DISTANCE - distance + 1;
-                } else {
-                    // Safely walk outside the cheat are
-                    from_index += distance - CHEAT_DISTANCE;
-                }
+    /// ### Long Skips
+    /// 
+    /// Finds all cheats that travel at most 20 tiles and save at least 100 picoseconds
+    /// The entire path is precalculated from finish to start like in part one
+    /// Then the entire path is retraced, for each path tile the remaining path is scanned for skips
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut maze = input.maze.clone();
+        let finish = input.finish;
+        let min_skip_distance = input.min_save;
+        let cheat_distance = input.cheat_distance;
+
+        let mut pos = finish;
+        let mut path: Vec<(usize, usize)> = Vec::with_capacity(Maze::area() / 4);
+
+        // Go through the maze and keep track of the path
+        loop {
+            maze[pos] = VISITED;
+
+            path.push(Maze::to_coordinates(pos));
+
+            if maze[Maze::moved_horizontally(pos, 1)] == AIR {
+                pos += 1;
+            } else if maze[Maze::moved_horizontally(pos, -1)] == AIR {
+                pos -= 1;
+            } else if maze[Maze::moved_vertically(pos, 1)] == AIR {
+                pos += Maze::width();
+            } else if maze[Maze::moved_vertically(pos, -1)] == AIR {
+                pos -= Maze::width();
             } else {
-                // If were are close to the lower end of the remaining path and can't skip yet, advance slowly
-                from_index += 1;
+                // The start position has been found, as the path has ended
+                break;
             }
         }
 
-        // It is possible to overshoot the remaining path while bulk processing the cheats
-        if from_index > remaining_path.len() {
-            let higher_coords = remaining_path.last().unwrap();
-            let distance = to_coords.0.abs_diff(higher_coords.0) + to_coords.1.abs_diff(higher_coords.1);
-            
-            // If we overshot we need to check if the last point was a valid cheat
-            if distance <= CHEAT_DISTANCE {
-                // If the last point was counting cheats we need to subtract the overflow
-                good_cheats -= from_index - remaining_path.len();
+        let mut good_cheats = 0;
+
+        // Iterate through the entire path, except the last 100, where a good skip from a location further up is no longer possible
+        // We walk through the path with a location where we skip to. For each location to skip to, we check the path from 100 tiles further up
+        // for valid locations to jump from
+        for (to_index, to_coords) in path.iter().enumerate().take(path.len().saturating_sub(min_skip_distance)) {
+            let mut from_index = 0;
+
+            // The remaining path, where we can skip from, starts 100 tiles further up
+            let remaining_path = &path[to_index + min_skip_distance..];
+
+
+            while from_index < remaining_path.len() {
+                let from_coords = remaining_path[from_index];
+                // The distance of the two points is the manhattan distance
+                let distance = to_coords.0.abs_diff(from_coords.0) + to_coords.1.abs_diff(from_coords.1);
+
+                // If the distance between the points is greate than the index from the start of the remaining path,
+                // then we can't skip as the extra distance we need to walk puts us under 100 net save
+                if distance <= from_index {
+                    // We can always safely move at least the distance to the outer end of the diamond area, that marks where cheats are possible
+                    // From the inside we can be sure, that when we walk cheat_distance - distance + 1 tiles we will at most be on the rim of the area
+                    // Thus we don't need to count all tiles individually, and instead add them in bulk
+                    // When we are outside of the area we can walk at least cheat_distance - distance to end up at most right in front of the cheat area
+                    if distance <= cheat_distance {
+                        // Safely walk inside the cheat area
+                        good_cheats += cheat_distance - distance + 1;
+                        from_index += cheat_distance - distance + 1;
+                    } else {
+                        // Safely walk outside the cheat are
+                        from_index += distance - cheat_distance;
+                    }
+                } else {
+                    // If were are close to the lower end of the remaining path and can't skip yet, advance slowly
+                    from_index += 1;
+                }
+            }
+
+            // It is possible to overshoot the remaining path while bulk processing the cheats
+            if from_index > remaining_path.len() {
+                let higher_coords = remaining_path.last().unwrap();
+                let distance = to_coords.0.abs_diff(higher_coords.0) + to_coords.1.abs_diff(higher_coords.1);
+
+                // If we overshot we need to check if the last point was a valid cheat
+                if distance <= cheat_distance {
+                    // If the last point was counting cheats we need to subtract the overflow
+                    good_cheats -= from_index - remaining_path.len();
+                }
             }
+
         }
 
+        good_cheats
     }
-
-    good_cheats
 }
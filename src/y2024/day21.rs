@@ -1,10 +1,8 @@
-use std::fs;
-
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2024, 21}
+solutions!{2024, 21, "Keypad Conundrum", Day21}
 
 const X: u8 = 3 << 2;
 const Y: u8 = 3;
@@ -39,30 +37,6 @@ const DOWN_BUTTON: u8 = X_1 | Y_0;
 const RIGHT_BUTTON: u8 = X_0 | Y_0;
 const LEFT_BUTTON: u8 = X_2 | Y_0;
 
-fn get_input(file: &str) -> Vec<(usize, u32)> {
-    fs::read_to_string(file).expect("No file there").lines().map(|line| (line[..line.len() - 1].parse().unwrap(), {
-        let mut sequence: u32 = 0;
-        for button in line.chars() {
-            sequence = (sequence << 4) | match button {
-                'A' => KEY_A,
-                '0' => KEY_0,
-                '1' => KEY_1,
-                '2' => KEY_2,
-                '3' => KEY_3,
-                '4' => KEY_4,
-                '5' => KEY_5,
-                '6' => KEY_6,
-                '7' => KEY_7,
-                '8' => KEY_8,
-                '9' => KEY_9,
-                _ => panic!("Unknown code char {button}")
-            } as u32;
-        }
-
-        sequence
-    })).collect()
-}
-
 /// Checks how many buttons have to be pressed on the next keypad 
 /// to perform a sequence of button presses on the current keypad
 /// For each button to be pressed, this calculates the difference in position and creates a new sequence to move there
@@ -166,27 +140,59 @@ fn number_of_presses(sequence: u32, start: u8, depth: u8, cache: &mut FxHashMap<
     presses
 }
 
-/// ### Door with 2 Robots
-/// 
-/// Counts the number of presses for a sequence a human needs to enter into a control panel 
-/// to control a robot to input a sequence into a control panel
-/// to control a robot to input a sequence into a control panel
-/// to control a robot to input a sequence into a key pad
-/// to unlock a door
-fn solve_first(input: &[(usize, u32)]) -> u64 {
-    input.iter().map(|(num, sequence)| (num, {
-        number_of_presses(*sequence, KEY_A, 4, &mut FxHashMap::with_capacity_and_hasher(0x80, FxBuildHasher))
-    })).map(|(num, count)| *num as u64 * count).sum()
-}
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = Vec<(usize, u32)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| Ok((line.strip_suffix('A').ok_or(ParseError::Malformed("A code must end with A"))?.parse()?, {
+            let mut sequence: u32 = 0;
+            for button in line.chars() {
+                sequence = (sequence << 4) | match button {
+                    'A' => KEY_A,
+                    '0' => KEY_0,
+                    '1' => KEY_1,
+                    '2' => KEY_2,
+                    '3' => KEY_3,
+                    '4' => KEY_4,
+                    '5' => KEY_5,
+                    '6' => KEY_6,
+                    '7' => KEY_7,
+                    '8' => KEY_8,
+                    '9' => KEY_9,
+                    _ => return Err(ParseError::Malformed("Unknown code character"))
+                } as u32;
+            }
+
+            sequence
+        }))).collect()
+    }
 
-/// ### Door with 25 Robots
-/// 
-/// Counts the number of presses for a sequence a human needs to enter into a control panel 
-/// (to control a robot to input a sequence into a control panel) * 25
-/// to control a robot to input a sequence into a key pad
-/// to unlock a door
-fn solve_second(input: &[(usize, u32)]) -> u64 {
-    input.iter().map(|(num, sequence)| (num, {
-        number_of_presses(*sequence, KEY_A, 27, &mut FxHashMap::with_capacity_and_hasher(0x0800, FxBuildHasher))
-    })).map(|(num, count)| *num as u64 * count).sum()
+    /// ### Door with 2 Robots
+    /// 
+    /// Counts the number of presses for a sequence a human needs to enter into a control panel 
+    /// to control a robot to input a sequence into a control panel
+    /// to control a robot to input a sequence into a control panel
+    /// to control a robot to input a sequence into a key pad
+    /// to unlock a door
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter().map(|(num, sequence)| (num, {
+            number_of_presses(*sequence, KEY_A, 4, &mut FxHashMap::with_capacity_and_hasher(0x80, FxBuildHasher))
+        })).map(|(num, count)| *num as u64 * count).sum()
+    }
+
+    /// ### Door with 25 Robots
+    /// 
+    /// Counts the number of presses for a sequence a human needs to enter into a control panel 
+    /// (to control a robot to input a sequence into a control panel) * 25
+    /// to control a robot to input a sequence into a key pad
+    /// to unlock a door
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.iter().map(|(num, sequence)| (num, {
+            number_of_presses(*sequence, KEY_A, 27, &mut FxHashMap::with_capacity_and_hasher(0x0800, FxBuildHasher))
+        })).map(|(num, count)| *num as u64 * count).sum()
+    }
 }
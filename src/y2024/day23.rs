@@ -1,37 +1,13 @@
-use std::fs;
-
 use rustc_hash::{FxBuildHasher, FxHashSet};
 use petgraph::{graph::{NodeIndex, UnGraph}, visit::EdgeRef};
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2024, 23}
+solutions!{2024, 23, "LAN Party", Day23}
 
 type Triangles = Vec<[u16; 3]>;
 type NodeSet = FxHashSet<NodeIndex<u16>>;
 
-fn get_input(file: &str) -> (UnGraph<(), (), u16>, NodeSet) {
-    let graph = UnGraph::from_edges(
-        fs::read_to_string(file).expect("No file there").lines().map(|line| {
-            let mut characters = line.chars();
-
-            // A nodes index is the first char << 8 | the second char
-            let left = ((characters.next().unwrap() as u16) << 8) | characters.next().unwrap() as u16;
-            characters.next();
-            let right = ((characters.next().unwrap() as u16) << 8) | characters.next().unwrap() as u16;
-
-            (left, right)
-        })
-    );
-
-    // The graph contains 520 nodes, but the graph library fills in all nodes in between automatically
-    // So we save the nodes here as well, so that we don't process nodes that don't have any edges
-    let mut nodes = FxHashSet::with_capacity_and_hasher(520, FxBuildHasher);
-    nodes.extend(graph.raw_edges().iter().flat_map(|edge| [edge.source(), edge.target()]));
-
-    (graph, nodes)
-}
-
 /// An algorithm for finding triangles in a graph as described by [this paper](https://www.cs.cornell.edu/courses/cs6241/2019sp/readings/Chiba-1985-arboricity.pdf)
 /// 
 /// For each node in the graph, mark all its neighbors. For all marked nodes neighbors, check if they are marked
@@ -43,8 +19,8 @@ fn find_triangles(graph: &UnGraph<(), (), u16>, nodes: &NodeSet) -> Triangles {
 
     let mut marked: Vec<_> = vec![];
 
-    // There are 520 nodes in the graph, the last 2 can't form a triangle on their own
-    for &first_node in nodes.iter().take(518) {
+    // The last 2 nodes can't form a triangle on their own, as all edges to the other nodes have been removed
+    for &first_node in nodes.iter().take(nodes.len().saturating_sub(2)) {
         // Get all neighbors of the starting node
         marked.extend(graph.edges(first_node).map(|e| (e.id(), e.target())));
 
@@ -67,16 +43,6 @@ fn find_triangles(graph: &UnGraph<(), (), u16>, nodes: &NodeSet) -> Triangles {
     triangles
 }
 
-/// ### Triangles With T
-/// 
-/// Finds all triangles, that have at least one computer whose name starts with 't'
-fn solve_first(input: &(UnGraph<(), (), u16>, NodeSet)) -> usize {
-    find_triangles(&input.0, &input.1)
-        .iter()
-        .filter(|pc| (pc[0] >> 8) as u8 == b't' || (pc[1] >> 8) as u8 == b't' || (pc[2] >> 8) as u8 == b't')
-        .count()
-}
-
 /// The [Bron Kerbosch Algorithm](https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm)
 /// Finds the largest completely interconnected set of nodes - the largest clique - inside the graph
 fn bron_kerbosch(graph: &UnGraph<(), (), u16>, clique: NodeSet, mut candidates: NodeSet, mut excluded: NodeSet) -> Option<NodeSet> {
@@ -110,26 +76,67 @@ fn bron_kerbosch(graph: &UnGraph<(), (), u16>, clique: NodeSet, mut candidates:
     }
 }
 
-/// ### Largest Clique
-/// 
-/// Finds the largest clique (interconneced group of vertices)
-/// The names of the participating computers are then sorted and assembled into the password
-fn solve_second(input: &(UnGraph<(), (), u16>, NodeSet)) -> String {
-    let result = bron_kerbosch(&input.0, FxHashSet::default(), input.1.clone(), FxHashSet::default());
-
-    let mut pcs: Vec<NodeIndex<u16>> = result.unwrap().drain().collect();
-    pcs.sort();
-
-    let mut password = String::with_capacity(pcs.len() * 3);
-    
-    for pc in pcs {
-        password.push((pc.index() >> 8) as u8 as char);
-        password.push((pc.index() & 0xFF) as u8 as char);
-        password.push(',');
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = (UnGraph<(), (), u16>, NodeSet);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let edges = input.lines().map(|line| {
+            let &[left_high, left_low, b'-', right_high, right_low] = line.as_bytes() else {
+                return Err(ParseError::Malformed("A connection must have the format ab-cd"));
+            };
+
+            // A nodes index is the first char << 8 | the second char
+            let left = ((left_high as u16) << 8) | left_low as u16;
+            let right = ((right_high as u16) << 8) | right_low as u16;
+
+            Ok((left, right))
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        let graph = UnGraph::from_edges(edges);
+
+        // The real input contains 520 nodes, but the graph library fills in all nodes in between automatically
+        // So we save the nodes here as well, so that we don't process nodes that don't have any edges
+        let mut nodes = FxHashSet::with_capacity_and_hasher(520, FxBuildHasher);
+        nodes.extend(graph.raw_edges().iter().flat_map(|edge| [edge.source(), edge.target()]));
+
+        Ok((graph, nodes))
+    }
+
+    /// ### Triangles With T
+    /// 
+    /// Finds all triangles, that have at least one computer whose name starts with 't'
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        find_triangles(&input.0, &input.1)
+            .iter()
+            .filter(|pc| (pc[0] >> 8) as u8 == b't' || (pc[1] >> 8) as u8 == b't' || (pc[2] >> 8) as u8 == b't')
+            .count()
     }
 
-    // Remove the stray comma
-    password.pop().unwrap();
+    /// ### Largest Clique
+    /// 
+    /// Finds the largest clique (interconneced group of vertices)
+    /// The names of the participating computers are then sorted and assembled into the password
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let result = bron_kerbosch(&input.0, FxHashSet::default(), input.1.clone(), FxHashSet::default());
+
+        let mut pcs: Vec<NodeIndex<u16>> = result.unwrap().drain().collect();
+        pcs.sort();
 
-    password
+        let mut password = String::with_capacity(pcs.len() * 3);
+
+        for pc in pcs {
+            password.push((pc.index() >> 8) as u8 as char);
+            password.push((pc.index() & 0xFF) as u8 as char);
+            password.push(',');
+        }
+
+        // Remove the stray comma
+        password.pop().unwrap();
+
+        password
+    }
 }
@@ -1,13 +1,13 @@
-use std::{cmp::Ordering, fs, mem::swap, rc::Rc};
+use std::{cmp::Ordering, mem::swap, rc::Rc};
 
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use crate::solutions;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-solutions!{2024, 24}
+solutions!{2024, 24, "Crossed Wires", Day24}
 
 #[derive(Debug, Clone, Copy)]
-enum Equation {
+pub enum Equation {
     Value(bool),
     And(u32, u32),
     Or(u32, u32),
@@ -23,42 +23,6 @@ const fn string_to_gate(gate: &str) -> u32 {
         (bytes[2] as u32)
 }
 
-fn get_input(file: &str) -> FxHashMap<u32, Equation> {
-    let file = fs::read_to_string(file).expect("No file there");
-    
-    let mut connections = FxHashMap::default();
-    let (initial_values, equations) = file.split_once("\n\n").unwrap();
-
-    // Parses the initial values of the x and y gates
-    connections.extend(initial_values.lines().map(|s| {
-        let (gate, value) = s.split_once(": ").unwrap();
-        (string_to_gate(gate), Equation::Value(value.starts_with('1')))
-    }));
-
-    // Parses the connections from x and y up to z
-    connections.extend(equations.lines().map(|e| {
-        // Splits x AND y -> z into x AND y, z
-        let (operation, output) = e.split_once(" -> ").unwrap();
-        let gate = string_to_gate(output);
-
-        // Splits x AND y into x, AND, y
-        let operands: Vec<&str> = operation.split_whitespace().collect();
-        let left = string_to_gate(operands[0]);
-        let right = string_to_gate(operands[2]);
-
-        let equation = match operands[1] {
-            "AND" => Equation::And(left, right),
-            "OR" => Equation::Or(left, right),
-            "XOR" => Equation::Xor(left, right),
-            other => panic!("Unknown equation {other}",)
-        };
-
-        (gate, equation)
-    }));
-
-    connections
-}
-
 /// A Helper macro to get the already calculated output of a gate, or compute it if neccessary 
 macro_rules! cached_or_evaluate {
     ($gate: ident, $table: ident, $cache: ident) => {
@@ -105,20 +69,6 @@ const ZGATES: [u32; 46] = {
     zgates
 };
 
-/// ### Device Output
-/// 
-/// Evaluates all equations and produces a final number where bit at i is the value of zi
-fn solve_first(input: &FxHashMap<u32, Equation> ) -> u64 {
-    let mut output = 0;
-    let mut cache: FxHashMap<u32, bool> = FxHashMap::with_capacity_and_hasher(300, FxBuildHasher);
-
-    for (i, z_output_bit) in ZGATES.iter().enumerate() {
-        output |= (evaluate(input.get(z_output_bit).unwrap(), input, &mut cache) as u64) << i;
-    }
-
-    output
-}
-
 /// Expr in constrast to Equation stores direct references to its operands
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Expr {
@@ -303,24 +253,83 @@ fn gate_to_str(gate: u32) -> String {
     ].iter().collect()
 }
 
-/// ### Swapped Outputs
-/// 
-/// Finds the incorrect outputs in the full adder described by the equations and returns them as sequence
-fn solve_second(input: &FxHashMap<u32, Equation> ) -> String {
-    let mut cache: FxHashMap<u32, Rc<Expr>> = FxHashMap::with_capacity_and_hasher(400, FxBuildHasher);
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = FxHashMap<u32, Equation>;
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut connections = FxHashMap::default();
+        let (initial_values, equations) = input.split_once("\n\n").ok_or(ParseError::Malformed("Values and equations must be seperated by an empty line"))?;
+
+        // Parses the initial values of the x and y gates
+        for s in initial_values.lines() {
+            let (gate, value) = s.split_once(": ").ok_or(ParseError::Malformed("An initial value must have the format gate: value"))?;
+            connections.insert(string_to_gate(gate), Equation::Value(value.starts_with('1')));
+        }
+
+        // Parses the connections from x and y up to z
+        for e in equations.lines() {
+            // Splits x AND y -> z into x AND y, z
+            let (operation, output) = e.split_once(" -> ").ok_or(ParseError::Malformed("An equation must have an output"))?;
+            let gate = string_to_gate(output);
+
+            // Splits x AND y into x, AND, y
+            let &[left, operator, right] = operation.split_whitespace().collect::<Vec<&str>>().as_slice() else {
+                return Err(ParseError::Malformed("An equation must have two operands"));
+            };
 
-    for z_output_bit in ZGATES.iter() {
-        let expr = Rc::new(parse(*z_output_bit, input, &mut cache));
-        cache.insert(*z_output_bit, expr);
+            let left = string_to_gate(left);
+            let right = string_to_gate(right);
+
+            let equation = match operator {
+                "AND" => Equation::And(left, right),
+                "OR" => Equation::Or(left, right),
+                "XOR" => Equation::Xor(left, right),
+                _ => return Err(ParseError::Malformed("Unknown operator"))
+            };
+
+            connections.insert(gate, equation);
+        }
+
+        Ok(connections)
     }
 
-    let mut wrong = validate(&cache);
-    wrong.sort();
-    wrong.dedup();
+    /// ### Device Output
+    /// 
+    /// Evaluates all equations and produces a final number where bit at i is the value of zi
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut output = 0;
+        let mut cache: FxHashMap<u32, bool> = FxHashMap::with_capacity_and_hasher(300, FxBuildHasher);
 
-    wrong.iter()
-        .copied()
-        .map(&gate_to_str)
-        .collect::<Vec<String>>()
-        .join(",")
+        for (i, z_output_bit) in ZGATES.iter().enumerate() {
+            output |= (evaluate(input.get(z_output_bit).unwrap(), input, &mut cache) as u64) << i;
+        }
+
+        output
+    }
+
+    /// ### Swapped Outputs
+    /// 
+    /// Finds the incorrect outputs in the full adder described by the equations and returns them as sequence
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut cache: FxHashMap<u32, Rc<Expr>> = FxHashMap::with_capacity_and_hasher(400, FxBuildHasher);
+
+        for z_output_bit in ZGATES.iter() {
+            let expr = Rc::new(parse(*z_output_bit, input, &mut cache));
+            cache.insert(*z_output_bit, expr);
+        }
+
+        let mut wrong = validate(&cache);
+        wrong.sort();
+        wrong.dedup();
+
+        wrong.iter()
+            .copied()
+            .map(&gate_to_str)
+            .collect::<Vec<String>>()
+            .join(",")
+    }
 }
@@ -1,12 +1,6 @@
-use std::fs;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-use crate::solutions;
-
-solutions!{2024, 3}
-
-fn get_input(file: &str) -> String {
-    fs::read_to_string(file).expect("No file there")
-}
+solutions!{2024, 3, "Mull It Over", Day3}
 
 /// Describes how many characters where matched of the string
 /// This is needed, as when no character is matched, the caller may need to
@@ -196,20 +190,31 @@ impl Parser {
     
 }
 
-/// ### Sum of Products
-/// 
-/// Scans the input string for all mul(123,123) instructions and sums the resulting products
-fn solve_first(input: &str) -> u32 {
-    Parser::new(input)
-        .parse_muls()
-}
+pub struct Day3;
 
+impl Solver for Day3 {
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-/// ### Sum of Products with Awareness
-/// 
-/// Scans the input string for all mul(123,123) instructions and sums the resulting products,
-///    but stops recognising muls after parsing a dont'() instructions until a do() instruction is parsed
-fn solve_second(input: &str) -> u32 {
-    Parser::new(input)
-        .parse_muls_aware()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    /// ### Sum of Products
+    /// 
+    /// Scans the input string for all mul(123,123) instructions and sums the resulting products
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        Parser::new(input)
+            .parse_muls()
+    }
+
+    /// ### Sum of Products with Awareness
+    /// 
+    /// Scans the input string for all mul(123,123) instructions and sums the resulting products,
+    ///    but stops recognising muls after parsing a dont'() instructions until a do() instruction is parsed
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Parser::new(input)
+            .parse_muls_aware()
+    }
 }
@@ -1,48 +1,6 @@
-use std::fs;
+use crate::{error::ParseError, solutions, solver::Solver};
 
-use crate::solutions;
-
-solutions!{2024, 4}
-
-fn get_input(file: &str) -> Vec<Vec<char>> {
-    fs::read_to_string(file).expect("No file there").lines().map(|line| line.chars().collect()).collect()
-}
-
-/// ### XMAS Word Search
-/// 
-/// Find all occurences of the word "XMAS" in any direction on the grid
-fn solve_first(input: &[Vec<char>]) -> i32 {
-    let puzzle_height = input.len() as isize;
-    let puzzle_width = input[0].len() as isize;
-    let mut xmas_count = 0;
-
-    // Foreach character on the grid
-    for y in 0..puzzle_height {
-        for x in 0..puzzle_width {
-            // If that character is an 'X'
-            if input[y as usize][x as usize] == 'X' {
-                // Check in all directions
-                for dy in -1..=1 {
-                    for dx in -1..=1 {
-                        if 
-                            // Check if the 'S' fits inside the grid
-                            x + 3 * dx >= 0 && x + 3 * dx < puzzle_width &&
-                            y + 3 * dy >= 0 && y + 3 * dy < puzzle_height &&
-                            // Check if the next characters are MAS
-                            input[(y + dy) as usize][(x + dx) as usize] == 'M' &&
-                            input[(y + 2 * dy) as usize][(x + 2 * dx) as usize] == 'A' &&
-                            input[(y + 3 * dy) as usize][(x + 3 * dx) as usize] == 'S' 
-                        {
-                            xmas_count += 1;
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    xmas_count
-}
+solutions!{2024, 4, "Ceres Search", Day4}
 
 #[inline(always)]
 fn is_s_or_m(c: char) -> bool {
@@ -56,29 +14,77 @@ fn is_x_mas(tl: char, tr: char, bl: char, br: char) -> bool {
     is_s_or_m(tl) && is_s_or_m(tr) && is_s_or_m(bl) && is_s_or_m(br) && tl != br && tr != bl
 }
 
-/// ### X-Mas Word Search
-/// 
-/// Finds all MAS assembled in an X Shape in the puzzle grid
-fn solve_second(input: &[Vec<char>]) -> i32 {
-    let puzzle_height = input.len();
-    let puzzle_width = input[0].len();
-    
-    let mut xmas_count = 0;
-    for y in 1..puzzle_height - 1 {
-        for x in 1..puzzle_width - 1 {
-            // If we land on an 'A', check the surroundings for M's and S'
-            if input[y][x] == 'A' {
-                let tl = input[y - 1][x - 1];
-                let tr = input[y - 1][x + 1];
-                let bl = input[y + 1][x - 1];
-                let br = input[y + 1][x + 1];
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-                if is_x_mas(tl, tr, bl, br) {
-                    xmas_count += 1;       
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    /// ### XMAS Word Search
+    /// 
+    /// Find all occurences of the word "XMAS" in any direction on the grid
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let puzzle_height = input.len() as isize;
+        let puzzle_width = input[0].len() as isize;
+        let mut xmas_count = 0;
+
+        // Foreach character on the grid
+        for y in 0..puzzle_height {
+            for x in 0..puzzle_width {
+                // If that character is an 'X'
+                if input[y as usize][x as usize] == 'X' {
+                    // Check in all directions
+                    for dy in -1..=1 {
+                        for dx in -1..=1 {
+                            if 
+                                // Check if the 'S' fits inside the grid
+                                x + 3 * dx >= 0 && x + 3 * dx < puzzle_width &&
+                                y + 3 * dy >= 0 && y + 3 * dy < puzzle_height &&
+                                // Check if the next characters are MAS
+                                input[(y + dy) as usize][(x + dx) as usize] == 'M' &&
+                                input[(y + 2 * dy) as usize][(x + 2 * dx) as usize] == 'A' &&
+                                input[(y + 3 * dy) as usize][(x + 3 * dx) as usize] == 'S' 
+                            {
+                                xmas_count += 1;
+                            }
+                        }
+                    }
                 }
             }
         }
+
+        xmas_count
     }
 
-    xmas_count
+    /// ### X-Mas Word Search
+    /// 
+    /// Finds all MAS assembled in an X Shape in the puzzle grid
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let puzzle_height = input.len();
+        let puzzle_width = input[0].len();
+
+        let mut xmas_count = 0;
+        for y in 1..puzzle_height - 1 {
+            for x in 1..puzzle_width - 1 {
+                // If we land on an 'A', check the surroundings for M's and S'
+                if input[y][x] == 'A' {
+                    let tl = input[y - 1][x - 1];
+                    let tr = input[y - 1][x + 1];
+                    let bl = input[y + 1][x - 1];
+                    let br = input[y + 1][x + 1];
+
+                    if is_x_mas(tl, tr, bl, br) {
+                        xmas_count += 1;       
+                    }
+                }
+            }
+        }
+
+        xmas_count
+    }
 }
@@ -1,16 +1,16 @@
-use std::{fs, hash::Hash};
+use std::hash::Hash;
 
 use rustc_hash::{FxBuildHasher, FxHashSet};
 
-use crate::{solutions, util::flatgrid::FlatGrid};
+use crate::{error::ParseError, solutions, solver::Solver, util::flatgrid::FlatGrid};
 
-solutions!{2024, 6}
+solutions!{2024, 6, "Guard Gallivant", Day6}
 
 /// Saves a point and a direction, but hashes only the point
 /// This means when putting these into a hashset, the same point will be skipped,
 ///   if inserted a second time even for a different direction
 #[derive(Clone, Copy, Debug)]
-struct StealthDirection(usize, isize);
+pub struct StealthDirection(usize, isize);
 
 impl Hash for StealthDirection {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -153,86 +153,6 @@ fn next_position(map: &Room, start: usize, direction: isize, obstacle: usize) ->
     }
 }
 
-fn get_input(file: &str) -> DataCollection {
-    let file = fs::read_to_string(file).expect("No file there");
-    let lines: Vec<&str> = file.lines().collect();
-
-    let mut starting: usize = 0;
-
-    
-    // Collects all obstacles per axis
-    let mut obstacles_x = vec![vec![]; Room::width()];
-    let mut obstacles_y = vec![vec![]; Room::height()];
-    
-    for (y, line) in lines.iter().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            if char == '#' {
-                obstacles_x[x].push(y);
-                obstacles_y[y].push(x);
-            } else if char == '^' {
-                starting = Room::to_index(x, y);
-            }
-        }
-    }
-    
-    // Stores the next obstacles coordinate on that axis for each direction
-    let mut obstacles: Room = vec![0xFF00FF00; WIDTH * HEIGHT].into();
-
-    for (y, row) in obstacles_y.iter().enumerate() {
-        let mut last_left = Room::width();
-        for x in row.iter().rev() {
-            for i in *x..last_left {
-                obstacles[Room::to_index(i, y)] = obstacles[Room::to_index(i, y)] & 0x00FFFFFF | (*x as u32) << 24;
-            }
-            last_left = *x;
-        }
-
-        let mut last_right = 0;
-        for x in row {
-            for i in last_right..=*x {
-                obstacles[Room::to_index(i, y)] |= (*x as u32) << 16;
-            }
-            last_right = x + 1;
-        }
-    }
-
-    for (x, column) in obstacles_x.iter().enumerate() {
-
-        let mut last_down = Room::height();
-        for y in column.iter().rev() {
-            for i in *y..last_down {
-                obstacles[Room::to_index(x, i)] = obstacles[Room::to_index(x, i)] & 0xFFFF00FF | (*y as u32) << 8;
-            }
-            last_down = *y;
-        }
-
-        let mut last_up = 0;
-        for y in column {
-            for i in last_up..=*y {
-                obstacles[Room::to_index(x, i)] |= *y as u32;
-            }
-            last_up = y + 1;
-        }        
-    }
-
-    let output = (
-        starting,
-        obstacles
-    );
-
-    // Precalculate part 1 to reuse it in part 2
-    let shared_path = path_and_directions(&output);
-
-    (
-        output.0,
-        output.1,
-        shared_path.0,
-        shared_path.1
-    )
-
-    
-}
-
 /// Rotates the given direction 90° right
 #[inline(always)]
 fn rotate_right(direction: isize) -> isize {
@@ -279,55 +199,161 @@ fn path_and_directions(input: &(usize, Room)) -> (FxHashSet<usize>, FxHashSet<St
     (path, directions)
 }
 
-/// ### Path of the Guard
-/// 
-/// Uses the precomputed path and counts the tiles
-/// For the algorithm look at path_and_directions
-fn solve_first(input: &DataCollection) -> usize {
-    let (_, _, path, _) = input;
-
-    path.len()
-}
-
-/// ### Infinite Loops
-/// 
-/// Searches how many obstacles could be placed on the guards path to create an infinite loop
-fn solve_second(input: &DataCollection) -> usize {
-    let (starting_position, map, _, path) = input;
+/// Rooms that do not fit the fixed size are rejected instead of being indexed out of bounds
+///
+/// ```
+/// # use aoc::{solver::Solver, y2024::day6::Day6};
+/// let wide = format!("^{}", ".".repeat(130));
+/// assert!(Day6::parse(&wide).is_err());
+///
+/// let tall = "^\n".repeat(131);
+/// assert!(Day6::parse(&tall).is_err());
+///
+/// assert!(Day6::parse("^..\n..").is_err());
+/// ```
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input = DataCollection;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+
+        let mut starting: usize = 0;
+
+
+        // Collects all obstacles per axis
+        let mut obstacles_x = vec![vec![]; Room::width()];
+        let mut obstacles_y = vec![vec![]; Room::height()];
+
+        // Checks the size before anything is indexed, as the room has a fixed size
+        if lines.len() > HEIGHT || lines.iter().any(|line| line.len() > WIDTH) {
+            return Err(ParseError::Malformed("The room is larger than expected"));
+        }
 
-    let mut loop_obstacles = Vec::with_capacity(1024);
-    let mut trace_visited: Vec<u16> = Vec::with_capacity(32);
+        if lines.iter().any(|line| line.len() != lines[0].len()) {
+            return Err(ParseError::Malformed("The rows of the room differ in length"));
+        }
 
-    for &StealthDirection(potential_obstacle, starting_direction) in path {
-        // Don't place an obstacle at the guards starting position
-        if potential_obstacle == *starting_position {
-            continue;
+        for (y, line) in lines.iter().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                if char == '#' {
+                    obstacles_x[x].push(y);
+                    obstacles_y[y].push(x);
+                } else if char == '^' {
+                    starting = Room::to_index(x, y);
+                }
+            }
         }
 
-        let mut trace_position = (potential_obstacle as isize - starting_direction) as usize;
-        let mut trace_direction = starting_direction;
-        trace_visited.clear();
+        // Stores the next obstacles coordinate on that axis for each direction
+        let mut obstacles: Room = vec![0xFF00FF00; WIDTH * HEIGHT].into();
+
+        for (y, row) in obstacles_y.iter().enumerate() {
+            let mut last_left = Room::width();
+            for x in row.iter().rev() {
+                for i in *x..last_left {
+                    obstacles[Room::to_index(i, y)] = obstacles[Room::to_index(i, y)] & 0x00FFFFFF | (*x as u32) << 24;
+                }
+                last_left = *x;
+            }
 
-        let mut turns = 0;
+            let mut last_right = 0;
+            for x in row {
+                for i in last_right..=*x {
+                    obstacles[Room::to_index(i, y)] |= (*x as u32) << 16;
+                }
+                last_right = x + 1;
+            }
+        }
 
-        // While on the map, skip straight in front of the next obstacle
-        while let Some(trace_next) = next_position(map, trace_position, trace_direction, potential_obstacle) {
-            trace_position = trace_next;
+        for (x, column) in obstacles_x.iter().enumerate() {
 
-            // The guard can only be on a repeating position in the same direction every 4 turns - 360°, a full turn
-            if turns & 3 == 0 {
-                if trace_visited.contains(&(trace_position as u16)) {
-                        loop_obstacles.push(potential_obstacle);
-                        break;
-                } else {
-                    trace_visited.push(trace_position as u16);
+            let mut last_down = Room::height();
+            for y in column.iter().rev() {
+                for i in *y..last_down {
+                    obstacles[Room::to_index(x, i)] = obstacles[Room::to_index(x, i)] & 0xFFFF00FF | (*y as u32) << 8;
                 }
+                last_down = *y;
             }
 
-            turns += 1;
-            trace_direction = rotate_right(trace_direction);
+            let mut last_up = 0;
+            for y in column {
+                for i in last_up..=*y {
+                    obstacles[Room::to_index(x, i)] |= *y as u32;
+                }
+                last_up = y + 1;
+            }        
         }
+
+        let output = (
+            starting,
+            obstacles
+        );
+
+        // Precalculate part 1 to reuse it in part 2
+        let shared_path = path_and_directions(&output);
+
+        Ok((
+            output.0,
+            output.1,
+            shared_path.0,
+            shared_path.1
+        ))
+    }
+
+    /// ### Path of the Guard
+    /// 
+    /// Uses the precomputed path and counts the tiles
+    /// For the algorithm look at path_and_directions
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let (_, _, path, _) = input;
+
+        path.len()
     }
 
-    loop_obstacles.len()
+    /// ### Infinite Loops
+    /// 
+    /// Searches how many obstacles could be placed on the guards path to create an infinite loop
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let (starting_position, map, _, path) = input;
+
+        let mut loop_obstacles = Vec::with_capacity(1024);
+        let mut trace_visited: Vec<u16> = Vec::with_capacity(32);
+
+        for &StealthDirection(potential_obstacle, starting_direction) in path {
+            // Don't place an obstacle at the guards starting position
+            if potential_obstacle == *starting_position {
+                continue;
+            }
+
+            let mut trace_position = (potential_obstacle as isize - starting_direction) as usize;
+            let mut trace_direction = starting_direction;
+            trace_visited.clear();
+
+            let mut turns = 0;
+
+            // While on the map, skip straight in front of the next obstacle
+            while let Some(trace_next) = next_position(map, trace_position, trace_direction, potential_obstacle) {
+                trace_position = trace_next;
+
+                // The guard can only be on a repeating position in the same direction every 4 turns - 360°, a full turn
+                if turns & 3 == 0 {
+                    if trace_visited.contains(&(trace_position as u16)) {
+                            loop_obstacles.push(potential_obstacle);
+                            break;
+                    } else {
+                        trace_visited.push(trace_position as u16);
+                    }
+                }
+
+                turns += 1;
+                trace_direction = rotate_right(trace_direction);
+            }
+        }
+
+        loop_obstacles.len()
+    }
 }
@@ -1,20 +1,6 @@
-use std::fs;
-use crate::solutions;
-
-solutions!{2024, 7}
-
-fn get_input(file: &str) -> Vec<(i64, Vec<i64>)> {
-    fs::read_to_string(file)
-        .expect("No file there")
-        .lines()
-        .map(|line| line.split_once(": ").expect("No divider"))
-        .map(|(target, operands)| (
-            target.parse()
-                .expect("Target is not a number"), 
-            operands.split_whitespace()
-                .map(|operand| operand.parse().expect("Operand ist not a number")).rev().collect()
-        )).collect()
-}
+use crate::{error::ParseError, solutions, solver::Solver};
+
+solutions!{2024, 7, "Bridge Repair", Day7}
 
 /// Checks if targets ends in operand, like how 123 ends in 23
 #[inline]
@@ -67,18 +53,6 @@ fn is_possible(target: i64, rest: &[i64]) -> bool {
     }
 }
 
-/// ### Calculation Calibration
-/// 
-/// For each target number, test if it could be the result of a calculation 
-///     involving all operands and the operators + and *
-fn solve_first(input: &[(i64, Vec<i64>)]) -> i64 {
-    input.iter()
-        .map(|(target, operands)| (target, operands))
-        .filter(|(target, operands)| is_possible(**target, &operands[0..operands.len()]))
-        .map(|(target, _)| target)
-        .sum()
-}
-
 /// Like is_possible but has a third operator: concat
 fn is_possible_with_concat(target: i64, rest: &[i64]) -> bool {
     match rest {
@@ -102,14 +76,49 @@ fn is_possible_with_concat(target: i64, rest: &[i64]) -> bool {
     }
 }
 
-/// ### Calculation Calibration with Concat
-/// 
-/// For each target number, test if it could be the result of a calculation 
-///     involving all operands and the operators +, * and concat
-fn solve_second(input: &[(i64, Vec<i64>)]) -> i64 {
-    input.iter()
-        .map(|(target, operands)| (target, operands))
-        .filter(|(target, operands)| is_possible_with_concat(**target, &operands[0..operands.len()]))
-        .map(|(target, _)| target)
-        .sum()
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| line.split_once(": ").ok_or(ParseError::Malformed("No divider")))
+            .map(|split| {
+                let (target, operands) = split?;
+
+                Ok((
+                    target.parse()?,
+                    operands.split_whitespace()
+                        .map(|operand| operand.parse()).rev().collect::<Result<_, _>>()?
+                ))
+            }).collect()
+    }
+
+    /// ### Calculation Calibration
+    /// 
+    /// For each target number, test if it could be the result of a calculation 
+    ///     involving all operands and the operators + and *
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter()
+            .map(|(target, operands)| (target, operands))
+            .filter(|(target, operands)| is_possible(**target, &operands[0..operands.len()]))
+            .map(|(target, _)| target)
+            .sum()
+    }
+
+    /// ### Calculation Calibration with Concat
+    /// 
+    /// For each target number, test if it could be the result of a calculation 
+    ///     involving all operands and the operators +, * and concat
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.iter()
+            .map(|(target, operands)| (target, operands))
+            .filter(|(target, operands)| is_possible_with_concat(**target, &operands[0..operands.len()]))
+            .map(|(target, _)| target)
+            .sum()
+    }
 }